@@ -1,13 +1,13 @@
 #!/bin/sh
 
-ids=$(apiary --api-key ${HONEYCOMB_API_KEY} --dataset="theta" --endpoint="slos" | jq --raw-output '.[].id')
-
-for id in ${ids}
-do
-    name=$(apiary --api-key ${HONEYCOMB_API_KEY} --dataset="theta" --endpoint="slos" --id="${id}" | jq '.name')
-    budget_remaining="$(apiary --api-key ${HONEYCOMB_API_KEY} --dataset="theta" --endpoint="slos" --id="${id}" | jq --raw-output '.budget_remaining')"
-    
-    echo "
-        ${budget_remaining}% SLO budget remains for ${name}
-    "
-done
+# Print SLO budget remaining for every SLO in a dataset.
+# Usage: ./report_card.sh [dataset ...]
+
+if [ "$#" -eq 0 ]; then
+    set -- theta
+fi
+
+datasets=$(IFS=,; echo "$*")
+
+apiary slos report --dataset="${datasets}" --format=json |
+    jq --raw-output '.[] | "\(.budget_remaining // "unknown")% SLO budget remains for \(.name)"'
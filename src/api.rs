@@ -0,0 +1,95 @@
+use crate::client::HoneycombClient;
+use crate::common::{
+    CommandContext, DEFAULT_PRETTY_FORMAT, OutputFormat, pretty_print_json, read_json_file,
+};
+use crate::errors::ApiaryError;
+use anyhow::Result;
+use clap::Args;
+use reqwest::Method;
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Args)]
+pub struct ApiArgs {
+    /// HTTP method (GET, POST, PUT, PATCH, DELETE, ...)
+    pub method: String,
+    /// API path, e.g. /1/datasets or /2/teams/my-team/environments
+    pub path: String,
+    /// Request body (JSON file path or inline JSON)
+    #[arg(long)]
+    pub data: Option<String>,
+    /// Query parameter as key=value (repeatable)
+    #[arg(short, long = "param", value_name = "KEY=VALUE")]
+    pub params: Vec<String>,
+    /// Extra request header as key=value (repeatable)
+    #[arg(short = 'H', long = "header", value_name = "KEY=VALUE")]
+    pub headers: Vec<String>,
+    /// Output format
+    #[arg(short, long, default_value = DEFAULT_PRETTY_FORMAT)]
+    pub format: OutputFormat,
+}
+
+impl ApiArgs {
+    pub async fn execute(&self, client: &HoneycombClient, _context: &CommandContext) -> Result<()> {
+        let method = Method::from_bytes(self.method.to_uppercase().as_bytes()).map_err(|_| {
+            ApiaryError::ValidationError(format!("Invalid HTTP method '{}'", self.method))
+        })?;
+
+        if !self.path.starts_with('/') {
+            return Err(ApiaryError::ValidationError(format!(
+                "API path must start with '/', got '{}'",
+                self.path
+            ))
+            .into());
+        }
+
+        let params = parse_key_values(&self.params, "--param")?;
+        let headers = parse_key_values(&self.headers, "--header")?;
+
+        let body = match &self.data {
+            Some(data) if std::path::Path::new(data).exists() => Some(read_json_file(data)?),
+            Some(data) => Some(serde_json::from_str(data)?),
+            None => None,
+        };
+
+        let response = client
+            .request(
+                method,
+                &self.path,
+                (!params.is_empty()).then_some(&params),
+                (!headers.is_empty()).then_some(&headers),
+                body.as_ref(),
+            )
+            .await?;
+        let text = client.response_text(response, &self.path)?;
+
+        // Print non-JSON bodies verbatim so unmodelled endpoints still work
+        match serde_json::from_str::<Value>(&text) {
+            Ok(json) => match self.format {
+                OutputFormat::Json => println!("{}", serde_json::to_string(&json)?),
+                OutputFormat::Pretty | OutputFormat::Table => {
+                    println!("{}", pretty_print_json(&json)?)
+                }
+            },
+            Err(_) if text.is_empty() => {}
+            Err(_) => println!("{}", text),
+        }
+
+        Ok(())
+    }
+}
+
+/// Parse repeated `key=value` arguments
+pub fn parse_key_values(pairs: &[String], flag: &str) -> Result<HashMap<String, String>> {
+    pairs
+        .iter()
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+            _ => Err(ApiaryError::ValidationError(format!(
+                "Invalid {} '{}': expected KEY=VALUE",
+                flag, pair
+            ))
+            .into()),
+        })
+        .collect()
+}
@@ -0,0 +1,360 @@
+use crate::client::HoneycombClient;
+use crate::common::{
+    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, pretty_print_json,
+    read_json_file,
+};
+use crate::environments::{EnvironmentSelfLink, EnvironmentTimestamps};
+use crate::errors;
+use crate::pagination::{PageArgs, Pages, print_more_hint, write_json_list};
+use anyhow::Result;
+use clap::Subcommand;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Subcommand)]
+pub enum ApiKeyCommands {
+    /// List API keys in a team
+    List {
+        /// Team slug (uses HONEYCOMB_TEAM env var if not specified)
+        #[arg(short, long)]
+        team: Option<String>,
+        /// Only show keys of this type
+        #[arg(long = "type", value_enum)]
+        key_type: Option<ApiKeyType>,
+        /// Only show keys for this environment (ID or slug)
+        #[arg(short, long)]
+        environment: Option<String>,
+        #[command(flatten)]
+        page: PageArgs,
+        /// Output format
+        #[arg(short, long, default_value = DEFAULT_TABLE_FORMAT)]
+        format: OutputFormat,
+    },
+    /// Get a specific API key
+    Get {
+        /// Team slug (uses HONEYCOMB_TEAM env var if not specified)
+        #[arg(short, long)]
+        team: Option<String>,
+        /// API key ID
+        #[arg(short, long)]
+        id: String,
+        /// Output format
+        #[arg(short, long, default_value = DEFAULT_PRETTY_FORMAT)]
+        format: OutputFormat,
+    },
+    /// Create a new API key
+    Create {
+        /// Team slug (uses HONEYCOMB_TEAM env var if not specified)
+        #[arg(short, long)]
+        team: Option<String>,
+        /// API key data (JSON file path or inline JSON)
+        #[arg(long)]
+        data: String,
+        /// Output format
+        #[arg(short, long, default_value = DEFAULT_PRETTY_FORMAT)]
+        format: OutputFormat,
+    },
+    /// Update an API key
+    Update {
+        /// Team slug (uses HONEYCOMB_TEAM env var if not specified)
+        #[arg(short, long)]
+        team: Option<String>,
+        /// API key ID
+        #[arg(short, long)]
+        id: String,
+        /// API key data (JSON file path or inline JSON)
+        #[arg(long)]
+        data: String,
+        /// Output format
+        #[arg(short, long, default_value = DEFAULT_PRETTY_FORMAT)]
+        format: OutputFormat,
+    },
+    /// Delete an API key
+    Delete {
+        /// Team slug (uses HONEYCOMB_TEAM env var if not specified)
+        #[arg(short, long)]
+        team: Option<String>,
+        /// API key ID
+        #[arg(short, long)]
+        id: String,
+    },
+}
+
+#[derive(Debug, Clone, clap::ValueEnum)]
+pub enum ApiKeyType {
+    Ingest,
+    Configuration,
+}
+
+impl ApiKeyType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiKeyType::Ingest => "ingest",
+            ApiKeyType::Configuration => "configuration",
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ApiKeyData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub data_type: String,
+    pub attributes: ApiKeyAttributes,
+    pub relationships: Option<ApiKeyRelationships>,
+    pub links: Option<EnvironmentSelfLink>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ApiKeyAttributes {
+    pub name: Option<String>,
+    pub key_type: String,
+    pub disabled: Option<bool>,
+    pub secret: Option<String>,
+    pub permissions: Option<Value>,
+    pub timestamps: Option<EnvironmentTimestamps>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ApiKeyRelationships {
+    pub environment: Option<ApiKeyEnvironmentRelation>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ApiKeyEnvironmentRelation {
+    pub data: ApiKeyEnvironmentData,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ApiKeyEnvironmentData {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub data_type: String,
+}
+
+impl ApiKeyCommands {
+    pub async fn execute(&self, client: &HoneycombClient, context: &CommandContext) -> Result<()> {
+        match self {
+            ApiKeyCommands::List {
+                team,
+                key_type,
+                environment,
+                page,
+                format,
+            } => {
+                let effective_team = context.team(team.as_deref())?;
+                list_api_keys(
+                    client,
+                    effective_team,
+                    key_type.as_ref(),
+                    environment.as_deref(),
+                    page,
+                    format,
+                )
+                .await
+            }
+            ApiKeyCommands::Get { team, id, format } => {
+                let effective_team = context.team(team.as_deref())?;
+                get_api_key(client, effective_team, id, format).await
+            }
+            ApiKeyCommands::Create { team, data, format } => {
+                let effective_team = context.team(team.as_deref())?;
+                create_api_key(client, effective_team, data, format).await
+            }
+            ApiKeyCommands::Update {
+                team,
+                id,
+                data,
+                format,
+            } => {
+                let effective_team = context.team(team.as_deref())?;
+                update_api_key(client, effective_team, id, data, format).await
+            }
+            ApiKeyCommands::Delete { team, id } => {
+                let effective_team = context.team(team.as_deref())?;
+                delete_api_key(client, effective_team, id).await
+            }
+        }
+    }
+}
+
+/// Resolve an environment ID or slug to its environment ID
+async fn resolve_environment_id(
+    client: &HoneycombClient,
+    team: &str,
+    environment: &str,
+) -> Result<String> {
+    let path = format!("/2/teams/{}/environments", team);
+    let envs = Pages::new(client, &path, HashMap::new(), &PageArgs::all())
+        .collect()
+        .await?;
+
+    for env in &envs {
+        let id = env.get("id").and_then(|v| v.as_str());
+        let slug = env
+            .get("attributes")
+            .and_then(|a| a.get("slug"))
+            .and_then(|v| v.as_str());
+        if let Some(id) = id
+            && (id == environment || slug == Some(environment))
+        {
+            return Ok(id.to_string());
+        }
+    }
+
+    Err(anyhow::Error::new(errors::ApiaryError::NotFound {
+        resource: "Environment".to_string(),
+        identifier: environment.to_string(),
+    })
+    .context(errors::messages::environment_not_found(environment, team)))
+}
+
+async fn list_api_keys(
+    client: &HoneycombClient,
+    team: &str,
+    key_type: Option<&ApiKeyType>,
+    environment: Option<&str>,
+    page: &PageArgs,
+    format: &OutputFormat,
+) -> Result<()> {
+    let mut query_params = HashMap::new();
+    if let Some(key_type) = key_type {
+        query_params.insert("filter[type]".to_string(), key_type.as_str().to_string());
+    }
+    if let Some(env) = environment {
+        let env_id = resolve_environment_id(client, team, env).await?;
+        query_params.insert("filter[environment_id]".to_string(), env_id);
+    }
+
+    let path = format!("/2/teams/{}/api-keys", team);
+    let mut pages = Pages::new(client, &path, query_params, page);
+
+    match format {
+        OutputFormat::Json | OutputFormat::Pretty => write_json_list(&mut pages, format).await?,
+        OutputFormat::Table => {
+            println!(
+                "{:<25} {:<30} {:<15} {:<10} Environment",
+                "ID", "Name", "Type", "Disabled"
+            );
+            println!("{:-<100}", "");
+
+            while let Some(items) = pages.next_page().await? {
+                for item in items {
+                    if let Ok(key) = serde_json::from_value::<ApiKeyData>(item) {
+                        let environment = key
+                            .relationships
+                            .and_then(|r| r.environment)
+                            .map(|e| e.data.id)
+                            .unwrap_or_else(|| "N/A".to_string());
+                        println!(
+                            "{:<25} {:<30} {:<15} {:<10} {}",
+                            key.id,
+                            key.attributes.name.unwrap_or_default(),
+                            key.attributes.key_type,
+                            key.attributes.disabled.unwrap_or(false),
+                            environment
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    print_more_hint(&pages);
+    Ok(())
+}
+
+async fn get_api_key(
+    client: &HoneycombClient,
+    team: &str,
+    id: &str,
+    format: &OutputFormat,
+) -> Result<()> {
+    let path = format!("/2/teams/{}/api-keys/{}", team, id);
+    let response = client.get(&path, None).await?;
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(&response)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!("{}", pretty_print_json(&response)?);
+        }
+    }
+
+    Ok(())
+}
+
+async fn create_api_key(
+    client: &HoneycombClient,
+    team: &str,
+    data: &str,
+    format: &OutputFormat,
+) -> Result<()> {
+    let json_data = if std::path::Path::new(data).exists() {
+        read_json_file(data)?
+    } else {
+        serde_json::from_str(data)?
+    };
+
+    let path = format!("/2/teams/{}/api-keys", team);
+    let response = client.post(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(&response)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!("{}", pretty_print_json(&response)?);
+        }
+    }
+
+    Ok(())
+}
+
+async fn update_api_key(
+    client: &HoneycombClient,
+    team: &str,
+    id: &str,
+    data: &str,
+    format: &OutputFormat,
+) -> Result<()> {
+    let json_data = if std::path::Path::new(data).exists() {
+        read_json_file(data)?
+    } else {
+        serde_json::from_str(data)?
+    };
+
+    let path = format!("/2/teams/{}/api-keys/{}", team, id);
+    let response = client.patch(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(&response)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!("{}", pretty_print_json(&response)?);
+        }
+    }
+
+    Ok(())
+}
+
+async fn delete_api_key(client: &HoneycombClient, team: &str, id: &str) -> Result<()> {
+    let path = format!("/2/teams/{}/api-keys/{}", team, id);
+    client.delete(&path).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
+
+    println!("API key '{}' in team '{}' deleted successfully", id, team);
+
+    Ok(())
+}
@@ -1,12 +1,19 @@
 use crate::client::HoneycombClient;
-use crate::common::{CommandContext, DEFAULT_PRETTY_FORMAT, OutputFormat};
+use crate::common::{
+    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, pretty_print_json,
+};
+use crate::errors::{ApiaryError, messages};
+use crate::logging;
+use crate::region::{self, Region};
 use anyhow::Result;
 use clap::Subcommand;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use std::collections::BTreeMap;
 
 #[derive(Subcommand)]
 pub enum AuthCommands {
-    /// Validate an API key and get authentication information
+    /// Validate the configured API keys and get authentication information
     Validate {
         /// Output format
         #[arg(short, long, default_value = DEFAULT_PRETTY_FORMAT)]
@@ -14,6 +21,12 @@ pub enum AuthCommands {
     },
     /// Show information about the current API key type and requirements
     Info,
+    /// Check which region's API accepts the configured keys
+    Region {
+        /// Output format
+        #[arg(short, long, default_value = DEFAULT_TABLE_FORMAT)]
+        format: OutputFormat,
+    },
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -77,69 +90,241 @@ pub struct TeamAttributes {
     pub slug: String,
 }
 
+/// Response from the v1 `/1/auth` endpoint for configuration keys
+#[derive(Deserialize, Serialize, Debug)]
+pub struct V1AuthResponse {
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub key_type: Option<String>,
+    #[serde(default)]
+    pub api_key_access: BTreeMap<String, bool>,
+    pub environment: V1AuthEntity,
+    pub team: V1AuthEntity,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct V1AuthEntity {
+    pub name: String,
+    pub slug: String,
+}
+
 impl AuthCommands {
     pub async fn execute(&self, client: &HoneycombClient, _context: &CommandContext) -> Result<()> {
         match self {
             AuthCommands::Validate { format } => validate_auth(client, format).await,
             AuthCommands::Info => show_key_info(client).await,
+            AuthCommands::Region { format } => detect_region(client, format).await,
         }
     }
 }
 
 async fn validate_auth(client: &HoneycombClient, format: &OutputFormat) -> Result<()> {
-    let response = client.get("/2/auth", None).await?;
+    if !client.has_management_key() && !client.has_config_key() {
+        return Err(ApiaryError::AuthenticationRequired(messages::NO_API_KEYS.to_string()).into());
+    }
+
+    // Each key type is validated against its own endpoint; a rejected key
+    // doesn't stop the other one from being checked
+    let mut checks = Vec::new();
+    if client.has_management_key() {
+        checks.push(("management", client.get("/2/auth", None).await));
+    }
+    if client.has_config_key() {
+        checks.push(("configuration", client.get("/1/auth", None).await));
+    }
+
+    match format {
+        OutputFormat::Json | OutputFormat::Pretty => {
+            let results: Map<String, Value> = checks
+                .iter()
+                .map(|(key, result)| {
+                    let value = match result {
+                        Ok(response) => response.clone(),
+                        Err(err) => json!({ "error": format!("{:#}", err) }),
+                    };
+                    (key.to_string(), value)
+                })
+                .collect();
+            let results = Value::Object(results);
+            if matches!(format, OutputFormat::Json) {
+                println!("{}", serde_json::to_string(&results)?);
+            } else {
+                println!("{}", serde_json::to_string_pretty(&results)?);
+            }
+        }
+        OutputFormat::Table => {
+            for (i, (key, result)) in checks.iter().enumerate() {
+                if i > 0 {
+                    println!();
+                }
+                let label = if *key == "management" {
+                    "Management key"
+                } else {
+                    "Configuration key"
+                };
+                match result {
+                    Ok(response) => {
+                        println!("✅ {}: OK", label);
+                        println!();
+                        if *key == "management" {
+                            print_management_auth(response)?;
+                        } else {
+                            print_configuration_auth(response)?;
+                        }
+                    }
+                    Err(err) => println!("❌ {}: FAILED ({:#})", label, err),
+                }
+            }
+        }
+    }
+
+    // Report the first failure once both results have been shown
+    match checks
+        .into_iter()
+        .find_map(|(key, result)| result.err().map(|err| (key, err)))
+    {
+        Some(("management", err)) => Err(err.context("Management key validation failed")),
+        Some((_, err)) => Err(err.context("Configuration key validation failed")),
+        None => Ok(()),
+    }
+}
+
+async fn detect_region(client: &HoneycombClient, format: &OutputFormat) -> Result<()> {
+    if !client.has_management_key() && !client.has_config_key() {
+        return Err(ApiaryError::AuthenticationRequired(messages::NO_API_KEYS.to_string()).into());
+    }
+
+    let candidates: Vec<(String, String)> = Region::ALL
+        .iter()
+        .map(|region| (region.to_string(), region.api_url().to_string()))
+        .collect();
+    let probes = region::probe_regions(client, &candidates).await;
 
     match format {
         OutputFormat::Json => {
-            println!("{}", serde_json::to_string(&response)?);
+            println!("{}", serde_json::to_string(&probes)?);
         }
         OutputFormat::Pretty => {
-            println!("{}", serde_json::to_string_pretty(&response)?);
+            println!("{}", pretty_print_json(&serde_json::to_value(&probes)?)?);
         }
         OutputFormat::Table => {
-            // Parse the v2 auth response
-            if let Ok(auth_response) = serde_json::from_value::<AuthResponse>(response.clone()) {
-                println!("API Key Information:");
-                println!("==================");
-                println!("Name: {}", auth_response.data.attributes.name);
-                println!("Type: {}", auth_response.data.attributes.key_type);
-                println!("ID: {}", auth_response.data.id);
-                println!(
-                    "Status: {}",
-                    if auth_response.data.attributes.disabled {
-                        "Disabled"
-                    } else {
-                        "Active"
-                    }
-                );
-                println!(
-                    "Created: {}",
-                    auth_response.data.attributes.timestamps.created
-                );
+            println!(
+                "{:<8} {:<32} {:<12} Configuration",
+                "Region", "API URL", "Management"
+            );
+            println!("{:-<70}", "");
+            for probe in &probes {
+                let show = |key: &Option<region::KeyProbe>| {
+                    key.as_ref()
+                        .map_or_else(|| "-".to_string(), |key| key.to_string())
+                };
                 println!(
-                    "Updated: {}",
-                    auth_response.data.attributes.timestamps.updated
+                    "{:<8} {:<32} {:<12} {}",
+                    probe.region,
+                    probe.api_url,
+                    show(&probe.management),
+                    show(&probe.configuration)
                 );
-                println!();
-
-                if let Some(team) = auth_response.included.first() {
-                    println!("Team Information:");
-                    println!("=================");
-                    println!("Name: {}", team.attributes.name);
-                    println!("Slug: {}", team.attributes.slug);
-                    println!("ID: {}", team.id);
-                    println!();
-                }
+            }
+        }
+    }
 
-                println!("Scopes:");
-                println!("=======");
-                for scope in &auth_response.data.attributes.scopes {
-                    println!("  • {}", scope);
-                }
+    let accepted: Vec<&str> = probes
+        .iter()
+        .filter(|probe| probe.accepted())
+        .map(|probe| probe.region.as_str())
+        .collect();
+    match accepted.as_slice() {
+        [] => Err(ApiaryError::AuthenticationRequired(
+            "No region accepted the configured keys".to_string(),
+        )
+        .into()),
+        [region] => {
+            eprintln!(
+                "Keys are valid in region '{}'; use --region {}",
+                region, region
+            );
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn print_management_auth(response: &Value) -> Result<()> {
+    // Parse the v2 auth response
+    if let Ok(auth_response) = serde_json::from_value::<AuthResponse>(response.clone()) {
+        println!("API Key Information:");
+        println!("==================");
+        println!("Name: {}", auth_response.data.attributes.name);
+        println!("Type: {}", auth_response.data.attributes.key_type);
+        println!("ID: {}", auth_response.data.id);
+        println!(
+            "Status: {}",
+            if auth_response.data.attributes.disabled {
+                "Disabled"
             } else {
-                println!("{}", serde_json::to_string_pretty(&response)?);
+                "Active"
             }
+        );
+        println!(
+            "Created: {}",
+            auth_response.data.attributes.timestamps.created
+        );
+        println!(
+            "Updated: {}",
+            auth_response.data.attributes.timestamps.updated
+        );
+        println!();
+
+        if let Some(team) = auth_response.included.first() {
+            println!("Team Information:");
+            println!("=================");
+            println!("Name: {}", team.attributes.name);
+            println!("Slug: {}", team.attributes.slug);
+            println!("ID: {}", team.id);
+            println!();
         }
+
+        println!("Scopes:");
+        println!("=======");
+        for scope in &auth_response.data.attributes.scopes {
+            println!("  • {}", scope);
+        }
+    } else {
+        println!("{}", serde_json::to_string_pretty(response)?);
+    }
+
+    Ok(())
+}
+
+fn print_configuration_auth(response: &Value) -> Result<()> {
+    // Parse the v1 auth response
+    if let Ok(auth_response) = serde_json::from_value::<V1AuthResponse>(response.clone()) {
+        println!("Configuration Key Information:");
+        println!("==============================");
+        if let Some(id) = &auth_response.id {
+            println!("ID: {}", id);
+        }
+        if let Some(key_type) = &auth_response.key_type {
+            println!("Type: {}", key_type);
+        }
+        println!(
+            "Team: {} ({})",
+            auth_response.team.name, auth_response.team.slug
+        );
+        println!(
+            "Environment: {} ({})",
+            auth_response.environment.name, auth_response.environment.slug
+        );
+        println!();
+
+        println!("Access:");
+        println!("=======");
+        for (access, allowed) in &auth_response.api_key_access {
+            println!("  {} {}", if *allowed { "✅" } else { "❌" }, access);
+        }
+    } else {
+        println!("{}", serde_json::to_string_pretty(response)?);
     }
 
     Ok(())
@@ -153,7 +338,7 @@ async fn show_key_info(client: &HoneycombClient) -> Result<()> {
         if let Some(mgmt_key) = client.get_key_for_endpoint("/2/auth") {
             println!(
                 "Management Key: {} (for v2 endpoints)",
-                &mgmt_key[..8.min(mgmt_key.len())]
+                logging::mask_key(mgmt_key)
             );
             println!("✅ Can access v2 APIs (Bearer authentication)");
         }
@@ -167,7 +352,7 @@ async fn show_key_info(client: &HoneycombClient) -> Result<()> {
         if let Some(config_key) = client.get_key_for_endpoint("/1/datasets") {
             println!(
                 "Configuration Key: {} (for v1 endpoints)",
-                &config_key[..8.min(config_key.len())]
+                logging::mask_key(config_key)
             );
             println!("✅ Can access v1 APIs (X-Honeycomb-Team authentication)");
         }
@@ -1,7 +1,7 @@
 use crate::client::HoneycombClient;
 use crate::common::{
-    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, pretty_print_json,
-    read_json_file,
+    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, environment_client,
+    pretty_print_json, read_json_file,
 };
 use anyhow::Result;
 use clap::Subcommand;
@@ -12,8 +12,8 @@ use serde_json::Value;
 pub enum BoardCommands {
     /// List all boards
     List {
-        /// Environment slug (uses HONEYCOMB_ENVIRONMENT env var by default, can be overridden with this flag)
-        #[arg(short, long, env = "HONEYCOMB_ENVIRONMENT")]
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
         environment: Option<String>,
         /// Output format
         #[arg(short, long, default_value = DEFAULT_TABLE_FORMAT)]
@@ -21,6 +21,9 @@ pub enum BoardCommands {
     },
     /// Get a specific board
     Get {
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Board ID
         #[arg(short, long)]
         id: String,
@@ -30,6 +33,9 @@ pub enum BoardCommands {
     },
     /// Create a new board
     Create {
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Board data (JSON file path or inline JSON)
         #[arg(long)]
         data: String,
@@ -39,6 +45,9 @@ pub enum BoardCommands {
     },
     /// Update a board
     Update {
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Board ID
         #[arg(short, long)]
         id: String,
@@ -50,9 +59,81 @@ pub enum BoardCommands {
         format: OutputFormat,
     },
     /// Delete a board
+    Delete {
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
+        /// Board ID
+        #[arg(short, long)]
+        id: String,
+    },
+    /// Saved board view management
+    Views {
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long, global = true)]
+        environment: Option<String>,
+        #[command(subcommand)]
+        command: BoardViewCommands,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum BoardViewCommands {
+    /// List all views on a board
+    List {
+        /// Board ID
+        #[arg(short, long)]
+        board: String,
+        /// Output format
+        #[arg(short, long, default_value = DEFAULT_TABLE_FORMAT)]
+        format: OutputFormat,
+    },
+    /// Get a specific board view
+    Get {
+        /// Board ID
+        #[arg(short, long)]
+        board: String,
+        /// View ID
+        #[arg(short, long)]
+        id: String,
+        /// Output format
+        #[arg(short, long, default_value = DEFAULT_PRETTY_FORMAT)]
+        format: OutputFormat,
+    },
+    /// Create a new board view
+    Create {
+        /// Board ID
+        #[arg(short, long)]
+        board: String,
+        /// View data (JSON file path or inline JSON)
+        #[arg(long)]
+        data: String,
+        /// Output format
+        #[arg(short, long, default_value = DEFAULT_PRETTY_FORMAT)]
+        format: OutputFormat,
+    },
+    /// Update a board view
+    Update {
+        /// Board ID
+        #[arg(short, long)]
+        board: String,
+        /// View ID
+        #[arg(short, long)]
+        id: String,
+        /// View data (JSON file path or inline JSON)
+        #[arg(long)]
+        data: String,
+        /// Output format
+        #[arg(short, long, default_value = DEFAULT_PRETTY_FORMAT)]
+        format: OutputFormat,
+    },
+    /// Delete a board view
     Delete {
         /// Board ID
         #[arg(short, long)]
+        board: String,
+        /// View ID
+        #[arg(short, long)]
         id: String,
     },
 }
@@ -86,49 +167,76 @@ pub struct BoardQuery {
     pub graphic_settings: Option<Value>,
 }
 
+#[derive(Deserialize, Serialize, Debug)]
+pub struct BoardView {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub filters: Vec<BoardViewFilter>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct BoardViewFilter {
+    pub column: String,
+    pub operation: String,
+    pub value: Option<Value>,
+}
+
 impl BoardCommands {
-    pub async fn execute(&self, client: &HoneycombClient, _context: &CommandContext) -> Result<()> {
+    fn environment(&self) -> Option<&str> {
         match self {
-            BoardCommands::List {
-                environment,
-                format,
-            } => list_boards(client, environment, format).await,
-            BoardCommands::Get { id, format } => get_board(client, id, format).await,
-            BoardCommands::Create { data, format } => create_board(client, data, format).await,
-            BoardCommands::Update { id, data, format } => {
-                update_board(client, id, data, format).await
-            }
-            BoardCommands::Delete { id } => delete_board(client, id).await,
+            BoardCommands::List { environment, .. }
+            | BoardCommands::Get { environment, .. }
+            | BoardCommands::Create { environment, .. }
+            | BoardCommands::Update { environment, .. }
+            | BoardCommands::Delete { environment, .. }
+            | BoardCommands::Views { environment, .. } => environment.as_deref(),
         }
     }
-}
 
-async fn list_boards(
-    client: &HoneycombClient,
-    environment: &Option<String>,
-    format: &OutputFormat,
-) -> Result<()> {
-    use crate::common::require_valid_environment;
-    use std::collections::HashMap;
+    pub async fn execute(&self, client: &HoneycombClient, context: &CommandContext) -> Result<()> {
+        let client = &environment_client(client, context, self.environment()).await?;
 
-    // Get environment from flag or env var (clap handles env var automatically via env = "HONEYCOMB_ENVIRONMENT")
-    let env = environment.as_ref().ok_or_else(|| {
-        anyhow::anyhow!(
-            "Environment is required. Set HONEYCOMB_ENVIRONMENT environment variable or use --environment flag."
-        )
-    })?;
-
-    // Validate environment exists
-    let team = std::env::var("HONEYCOMB_TEAM").unwrap_or_else(|_| "default".to_string());
-    require_valid_environment(client, &team, env).await?;
-
-    let path = "/1/boards";
+        match self {
+            BoardCommands::List { format, .. } => list_boards(client, format).await,
+            BoardCommands::Get { id, format, .. } => get_board(client, id, format).await,
+            BoardCommands::Create { data, format, .. } => create_board(client, data, format).await,
+            BoardCommands::Update {
+                id, data, format, ..
+            } => update_board(client, id, data, format).await,
+            BoardCommands::Delete { id, .. } => delete_board(client, id).await,
+            BoardCommands::Views { command, .. } => command.execute(client).await,
+        }
+    }
+}
 
-    // Always add environment as query parameter
-    let mut query_params = HashMap::new();
-    query_params.insert("environment".to_string(), env.to_string());
+impl BoardViewCommands {
+    pub async fn execute(&self, client: &HoneycombClient) -> Result<()> {
+        match self {
+            BoardViewCommands::List { board, format } => {
+                list_board_views(client, board, format).await
+            }
+            BoardViewCommands::Get { board, id, format } => {
+                get_board_view(client, board, id, format).await
+            }
+            BoardViewCommands::Create {
+                board,
+                data,
+                format,
+            } => create_board_view(client, board, data, format).await,
+            BoardViewCommands::Update {
+                board,
+                id,
+                data,
+                format,
+            } => update_board_view(client, board, id, data, format).await,
+            BoardViewCommands::Delete { board, id } => delete_board_view(client, board, id).await,
+        }
+    }
+}
 
-    let response = client.get(path, Some(&query_params)).await?;
+async fn list_boards(client: &HoneycombClient, format: &OutputFormat) -> Result<()> {
+    let response = client.get("/1/boards", None).await?;
 
     match format {
         OutputFormat::Json => {
@@ -232,6 +340,9 @@ async fn create_board(client: &HoneycombClient, data: &str, format: &OutputForma
     };
 
     let response = client.post("/1/boards", &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -259,6 +370,9 @@ async fn update_board(
 
     let path = format!("/1/boards/{}", id);
     let response = client.put(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -275,8 +389,146 @@ async fn update_board(
 async fn delete_board(client: &HoneycombClient, id: &str) -> Result<()> {
     let path = format!("/1/boards/{}", id);
     client.delete(&path).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     println!("Board '{}' deleted successfully", id);
 
     Ok(())
 }
+
+async fn list_board_views(
+    client: &HoneycombClient,
+    board: &str,
+    format: &OutputFormat,
+) -> Result<()> {
+    let path = format!("/1/boards/{}/views", board);
+    let response = client.get(&path, None).await?;
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(&response)?);
+        }
+        OutputFormat::Pretty => {
+            println!("{}", pretty_print_json(&response)?);
+        }
+        OutputFormat::Table => {
+            if let Value::Array(views) = response {
+                println!("{:<15} {:<30} Filters", "ID", "Name");
+                println!("{:-<80}", "");
+
+                for view in views {
+                    if let Ok(v) = serde_json::from_value::<BoardView>(view) {
+                        let filters = v
+                            .filters
+                            .iter()
+                            .map(|f| match &f.value {
+                                Some(value) => format!("{} {} {}", f.column, f.operation, value),
+                                None => format!("{} {}", f.column, f.operation),
+                            })
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        println!("{:<15} {:<30} {}", v.id, v.name, filters);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn get_board_view(
+    client: &HoneycombClient,
+    board: &str,
+    id: &str,
+    format: &OutputFormat,
+) -> Result<()> {
+    let path = format!("/1/boards/{}/views/{}", board, id);
+    let response = client.get(&path, None).await?;
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(&response)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!("{}", pretty_print_json(&response)?);
+        }
+    }
+
+    Ok(())
+}
+
+async fn create_board_view(
+    client: &HoneycombClient,
+    board: &str,
+    data: &str,
+    format: &OutputFormat,
+) -> Result<()> {
+    let json_data = if std::path::Path::new(data).exists() {
+        read_json_file(data)?
+    } else {
+        serde_json::from_str(data)?
+    };
+
+    let path = format!("/1/boards/{}/views", board);
+    let response = client.post(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(&response)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!("{}", pretty_print_json(&response)?);
+        }
+    }
+
+    Ok(())
+}
+
+async fn update_board_view(
+    client: &HoneycombClient,
+    board: &str,
+    id: &str,
+    data: &str,
+    format: &OutputFormat,
+) -> Result<()> {
+    let json_data = if std::path::Path::new(data).exists() {
+        read_json_file(data)?
+    } else {
+        serde_json::from_str(data)?
+    };
+
+    let path = format!("/1/boards/{}/views/{}", board, id);
+    let response = client.put(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(&response)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!("{}", pretty_print_json(&response)?);
+        }
+    }
+
+    Ok(())
+}
+
+async fn delete_board_view(client: &HoneycombClient, board: &str, id: &str) -> Result<()> {
+    let path = format!("/1/boards/{}/views/{}", board, id);
+    client.delete(&path).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
+
+    println!("View '{}' on board '{}' deleted successfully", id, board);
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use crate::client::HoneycombClient;
 use crate::common::{
-    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, pretty_print_json,
-    read_json_file,
+    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, environment_client,
+    pretty_print_json, read_json_file,
 };
 use anyhow::Result;
 use clap::Subcommand;
@@ -16,7 +16,7 @@ pub enum BurnAlertCommands {
         #[arg(short, long)]
         dataset: String,
         /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
-        #[arg(short, long, env = "HONEYCOMB_ENVIRONMENT")]
+        #[arg(short, long)]
         environment: Option<String>,
         /// Output format
         #[arg(short, long, default_value = DEFAULT_TABLE_FORMAT)]
@@ -27,6 +27,9 @@ pub enum BurnAlertCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Burn Alert ID
         #[arg(short, long)]
         id: String,
@@ -39,6 +42,9 @@ pub enum BurnAlertCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Burn alert data (JSON file path or inline JSON)
         #[arg(long)]
         data: String,
@@ -51,6 +57,9 @@ pub enum BurnAlertCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Burn Alert ID
         #[arg(short, long)]
         id: String,
@@ -66,6 +75,9 @@ pub enum BurnAlertCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Burn Alert ID
         #[arg(short, long)]
         id: String,
@@ -86,30 +98,43 @@ pub struct BurnAlert {
 }
 
 impl BurnAlertCommands {
-    pub async fn execute(&self, client: &HoneycombClient, _context: &CommandContext) -> Result<()> {
+    fn environment(&self) -> Option<&str> {
+        match self {
+            BurnAlertCommands::List { environment, .. }
+            | BurnAlertCommands::Get { environment, .. }
+            | BurnAlertCommands::Create { environment, .. }
+            | BurnAlertCommands::Update { environment, .. }
+            | BurnAlertCommands::Delete { environment, .. } => environment.as_deref(),
+        }
+    }
+
+    pub async fn execute(&self, client: &HoneycombClient, context: &CommandContext) -> Result<()> {
+        let client = &environment_client(client, context, self.environment()).await?;
+
         match self {
             BurnAlertCommands::List {
-                dataset,
-                environment,
-                format,
-            } => list_burn_alerts(client, dataset, environment.as_deref(), format).await,
+                dataset, format, ..
+            } => list_burn_alerts(client, dataset, format).await,
             BurnAlertCommands::Get {
                 dataset,
                 id,
                 format,
+                ..
             } => get_burn_alert(client, dataset, id, format).await,
             BurnAlertCommands::Create {
                 dataset,
                 data,
                 format,
+                ..
             } => create_burn_alert(client, dataset, data, format).await,
             BurnAlertCommands::Update {
                 dataset,
                 id,
                 data,
                 format,
+                ..
             } => update_burn_alert(client, dataset, id, data, format).await,
-            BurnAlertCommands::Delete { dataset, id } => {
+            BurnAlertCommands::Delete { dataset, id, .. } => {
                 delete_burn_alert(client, dataset, id).await
             }
         }
@@ -119,36 +144,10 @@ impl BurnAlertCommands {
 async fn list_burn_alerts(
     client: &HoneycombClient,
     dataset: &str,
-    environment: Option<&str>,
     format: &OutputFormat,
 ) -> Result<()> {
-    use crate::common::require_valid_environment;
-    use std::collections::HashMap;
-
-    // If environment is provided, validate it exists
-    if let Some(env) = environment {
-        let team = std::env::var("HONEYCOMB_TEAM").unwrap_or_else(|_| "default".to_string());
-        require_valid_environment(client, &team, env).await?;
-    }
-
     let path = format!("/1/burn_alerts/{}", dataset);
-
-    // Add environment as query parameter if provided
-    let mut query_params = HashMap::new();
-    if let Some(env) = environment {
-        query_params.insert("environment".to_string(), env.to_string());
-    }
-
-    let response = client
-        .get(
-            &path,
-            if query_params.is_empty() {
-                None
-            } else {
-                Some(&query_params)
-            },
-        )
-        .await?;
+    let response = client.get(&path, None).await?;
 
     match format {
         OutputFormat::Json => {
@@ -220,6 +219,9 @@ async fn create_burn_alert(
 
     let path = format!("/1/burn_alerts/{}", dataset);
     let response = client.post(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -248,6 +250,9 @@ async fn update_burn_alert(
 
     let path = format!("/1/burn_alerts/{}/{}", dataset, id);
     let response = client.put(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -264,6 +269,9 @@ async fn update_burn_alert(
 async fn delete_burn_alert(client: &HoneycombClient, dataset: &str, id: &str) -> Result<()> {
     let path = format!("/1/burn_alerts/{}/{}", dataset, id);
     client.delete(&path).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     println!(
         "Burn Alert '{}' in dataset '{}' deleted successfully",
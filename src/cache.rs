@@ -0,0 +1,240 @@
+use crate::dry_run;
+use crate::logging::is_sensitive_header;
+use crate::transport::{HttpRequest, HttpResponse};
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::debug;
+
+/// Extension of cache entry files
+const ENTRY_EXTENSION: &str = "json";
+
+/// Extension of an entry being written, followed by the writer's process ID
+const TMP_EXTENSION_PREFIX: &str = "tmp";
+
+/// GET endpoints polled until a result is ready; each answer may differ
+const POLLED_PATHS: [&str; 2] = ["/1/query_results/", "/1/maps/dependencies/requests/"];
+
+/// Event ingestion endpoints; sending events leaves configuration untouched
+const INGEST_PATHS: [&str; 2] = ["/1/events/", "/1/batch/"];
+
+#[derive(Subcommand)]
+pub enum CacheCommands {
+    /// Remove all cached responses
+    Clear,
+}
+
+impl CacheCommands {
+    pub fn execute(&self, cache_dir: Option<&Path>) -> Result<()> {
+        match self {
+            CacheCommands::Clear => {
+                let removed = match cache_dir {
+                    Some(dir) => ResponseCache::clear(dir)?,
+                    None => 0,
+                };
+                println!("Removed {} cached responses", removed);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// `$XDG_CACHE_HOME/apiary`, falling back to `~/.cache/apiary`
+pub fn default_cache_dir() -> Option<PathBuf> {
+    std::env::var_os("XDG_CACHE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .map(|dir| dir.join("apiary"))
+}
+
+/// On-disk cache of successful GET responses.
+///
+/// Entries are keyed by API URL, team, environment, a fingerprint of the API
+/// key sent with the request, and the path including the query string; the
+/// key itself is never stored. Any successful configuration change through
+/// the client clears the cache, so scripts don't read their own stale writes.
+/// That holds for a bypassed cache too, so a later run can't read stale data.
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    dir: PathBuf,
+    ttl: Duration,
+    team: Option<String>,
+    environment: Option<String>,
+    bypass: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Entry {
+    /// Seconds since the Unix epoch when the response was stored
+    stored_at: u64,
+    /// Request path, checked on read to rule out hash collisions
+    path: String,
+    status: u16,
+    body: String,
+}
+
+impl ResponseCache {
+    pub fn new(dir: impl Into<PathBuf>, ttl: Duration) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl,
+            team: None,
+            environment: None,
+            bypass: false,
+        }
+    }
+
+    /// Neither read nor store entries (`--no-cache`, or no TTL set), while
+    /// still clearing the cache after configuration changes
+    pub fn bypass(mut self, bypass: bool) -> Self {
+        self.bypass = bypass;
+        self
+    }
+
+    /// Keep entries for different teams and environments apart
+    pub fn scope(mut self, team: Option<String>, environment: Option<String>) -> Self {
+        self.team = team;
+        self.environment = environment;
+        self
+    }
+
+    /// Whether responses to a request may be cached: GETs other than the
+    /// status polls of query results and service maps
+    pub fn is_cacheable(request: &HttpRequest) -> bool {
+        let path = request.url.path();
+        request.method == Method::GET && !POLLED_PATHS.iter().any(|p| path.starts_with(p))
+    }
+
+    /// Whether a successful request changes configuration and so makes cached
+    /// responses stale. Read-only POSTs and event ingestion don't.
+    pub fn is_invalidated_by(request: &HttpRequest) -> bool {
+        let path = request.url.path();
+        dry_run::is_mutating(&request.method, path)
+            && !INGEST_PATHS.iter().any(|p| path.starts_with(p))
+    }
+
+    /// Cached response for a GET request, if one is stored and still fresh
+    pub fn get(&self, request: &HttpRequest) -> Option<HttpResponse> {
+        if self.bypass {
+            return None;
+        }
+        let path = self.entry_path(request);
+        let content = std::fs::read_to_string(&path).ok()?;
+        let entry: Entry = serde_json::from_str(&content).ok()?;
+
+        let age = now_secs().saturating_sub(entry.stored_at);
+        if entry.path != request.path_and_query() || age >= self.ttl.as_secs() {
+            return None;
+        }
+        debug!("Cache hit for {} ({}s old)", entry.path, age);
+
+        Some(HttpResponse {
+            status: StatusCode::from_u16(entry.status).ok()?,
+            headers: HeaderMap::new(),
+            body: entry.body.into_bytes(),
+        })
+    }
+
+    /// Store a successful response. Failures are logged and otherwise ignored,
+    /// since a cache that can't be written shouldn't fail the command.
+    pub fn put(&self, request: &HttpRequest, response: &HttpResponse) {
+        if self.bypass || !response.status.is_success() {
+            return;
+        }
+        let entry = Entry {
+            stored_at: now_secs(),
+            path: request.path_and_query(),
+            status: response.status.as_u16(),
+            body: response.text(),
+        };
+        if let Err(e) = self.write_entry(&self.entry_path(request), &entry) {
+            debug!("Failed to write cache entry: {:#}", e);
+        }
+    }
+
+    /// Drop every cached response after a write, ignoring errors
+    pub fn invalidate(&self) {
+        if let Err(e) = Self::clear(&self.dir) {
+            debug!("Failed to clear response cache: {:#}", e);
+        }
+    }
+
+    /// Remove all entries from a cache directory, returning how many were
+    /// removed. Temporary files left by an interrupted write go too.
+    pub fn clear(dir: &Path) -> Result<usize> {
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => {
+                return Err(e).with_context(|| {
+                    format!("Failed to read cache directory '{}'", dir.display())
+                });
+            }
+        };
+
+        let mut removed = 0;
+        for entry in entries {
+            let path = entry?.path();
+            let Some(ext) = path.extension().and_then(|ext| ext.to_str()) else {
+                continue;
+            };
+            let is_entry = ext == ENTRY_EXTENSION;
+            if is_entry || ext.starts_with(TMP_EXTENSION_PREFIX) {
+                std::fs::remove_file(&path)
+                    .with_context(|| format!("Failed to remove '{}'", path.display()))?;
+                removed += usize::from(is_entry);
+            }
+        }
+        Ok(removed)
+    }
+
+    fn entry_path(&self, request: &HttpRequest) -> PathBuf {
+        // DefaultHasher isn't stable across Rust releases; a toolchain upgrade
+        // just means a cold cache.
+        let mut hasher = DefaultHasher::new();
+        request.url.origin().ascii_serialization().hash(&mut hasher);
+        self.team.hash(&mut hasher);
+        self.environment.hash(&mut hasher);
+        for (name, value) in &request.headers {
+            if is_sensitive_header(name.as_str()) {
+                name.as_str().hash(&mut hasher);
+                value.as_bytes().hash(&mut hasher);
+            }
+        }
+        request.path_and_query().hash(&mut hasher);
+
+        self.dir
+            .join(format!("{:016x}.{}", hasher.finish(), ENTRY_EXTENSION))
+    }
+
+    fn write_entry(&self, path: &Path, entry: &Entry) -> Result<()> {
+        std::fs::create_dir_all(&self.dir).with_context(|| {
+            format!("Failed to create cache directory '{}'", self.dir.display())
+        })?;
+        // Cached bodies may include sensitive configuration; keep them private
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&self.dir, std::fs::Permissions::from_mode(0o700))?;
+        }
+        // Write then rename so concurrent readers never see a partial entry
+        let tmp = path.with_extension(format!("{}{}", TMP_EXTENSION_PREFIX, std::process::id()));
+        std::fs::write(&tmp, serde_json::to_vec(entry)?)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
@@ -1,7 +1,7 @@
 use crate::client::HoneycombClient;
 use crate::common::{
-    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, pretty_print_json,
-    read_json_file,
+    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, environment_client,
+    pretty_print_json, read_json_file,
 };
 use anyhow::Result;
 use clap::Subcommand;
@@ -16,7 +16,7 @@ pub enum CalculatedFieldCommands {
         #[arg(short, long)]
         dataset: String,
         /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
-        #[arg(short, long, env = "HONEYCOMB_ENVIRONMENT")]
+        #[arg(short, long)]
         environment: Option<String>,
         /// Output format
         #[arg(short, long, default_value = DEFAULT_TABLE_FORMAT)]
@@ -27,6 +27,9 @@ pub enum CalculatedFieldCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Calculated Field ID
         #[arg(short, long)]
         id: String,
@@ -39,6 +42,9 @@ pub enum CalculatedFieldCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Calculated field data (JSON file path or inline JSON)
         #[arg(long)]
         data: String,
@@ -51,6 +57,9 @@ pub enum CalculatedFieldCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Calculated Field ID
         #[arg(short, long)]
         id: String,
@@ -66,6 +75,9 @@ pub enum CalculatedFieldCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Calculated Field ID
         #[arg(short, long)]
         id: String,
@@ -83,30 +95,43 @@ pub struct CalculatedField {
 }
 
 impl CalculatedFieldCommands {
-    pub async fn execute(&self, client: &HoneycombClient, _context: &CommandContext) -> Result<()> {
+    fn environment(&self) -> Option<&str> {
+        match self {
+            CalculatedFieldCommands::List { environment, .. }
+            | CalculatedFieldCommands::Get { environment, .. }
+            | CalculatedFieldCommands::Create { environment, .. }
+            | CalculatedFieldCommands::Update { environment, .. }
+            | CalculatedFieldCommands::Delete { environment, .. } => environment.as_deref(),
+        }
+    }
+
+    pub async fn execute(&self, client: &HoneycombClient, context: &CommandContext) -> Result<()> {
+        let client = &environment_client(client, context, self.environment()).await?;
+
         match self {
             CalculatedFieldCommands::List {
-                dataset,
-                environment,
-                format,
-            } => list_calculated_fields(client, dataset, environment.as_deref(), format).await,
+                dataset, format, ..
+            } => list_calculated_fields(client, dataset, format).await,
             CalculatedFieldCommands::Get {
                 dataset,
                 id,
                 format,
+                ..
             } => get_calculated_field(client, dataset, id, format).await,
             CalculatedFieldCommands::Create {
                 dataset,
                 data,
                 format,
+                ..
             } => create_calculated_field(client, dataset, data, format).await,
             CalculatedFieldCommands::Update {
                 dataset,
                 id,
                 data,
                 format,
+                ..
             } => update_calculated_field(client, dataset, id, data, format).await,
-            CalculatedFieldCommands::Delete { dataset, id } => {
+            CalculatedFieldCommands::Delete { dataset, id, .. } => {
                 delete_calculated_field(client, dataset, id).await
             }
         }
@@ -116,36 +141,10 @@ impl CalculatedFieldCommands {
 async fn list_calculated_fields(
     client: &HoneycombClient,
     dataset: &str,
-    environment: Option<&str>,
     format: &OutputFormat,
 ) -> Result<()> {
-    use crate::common::require_valid_environment;
-    use std::collections::HashMap;
-
-    // If environment is provided, validate it exists
-    if let Some(env) = environment {
-        let team = std::env::var("HONEYCOMB_TEAM").unwrap_or_else(|_| "default".to_string());
-        require_valid_environment(client, &team, env).await?;
-    }
-
     let path = format!("/1/derived_columns/{}", dataset);
-
-    // Add environment as query parameter if provided
-    let mut query_params = HashMap::new();
-    if let Some(env) = environment {
-        query_params.insert("environment".to_string(), env.to_string());
-    }
-
-    let response = client
-        .get(
-            &path,
-            if query_params.is_empty() {
-                None
-            } else {
-                Some(&query_params)
-            },
-        )
-        .await?;
+    let response = client.get(&path, None).await?;
 
     match format {
         OutputFormat::Json => {
@@ -212,6 +211,9 @@ async fn create_calculated_field(
 
     let path = format!("/1/derived_columns/{}", dataset);
     let response = client.post(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -240,6 +242,9 @@ async fn update_calculated_field(
 
     let path = format!("/1/derived_columns/{}/{}", dataset, id);
     let response = client.put(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -256,6 +261,9 @@ async fn update_calculated_field(
 async fn delete_calculated_field(client: &HoneycombClient, dataset: &str, id: &str) -> Result<()> {
     let path = format!("/1/derived_columns/{}/{}", dataset, id);
     client.delete(&path).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     println!(
         "Calculated field '{}' in dataset '{}' deleted successfully",
@@ -0,0 +1,263 @@
+use crate::logging::{REDACTED, is_sensitive_header, redact_json};
+use crate::transport::{HttpRequest, HttpResponse, Transport, TransportFuture};
+use anyhow::{Context, Result};
+use flate2::read::GzDecoder;
+use reqwest::StatusCode;
+use reqwest::header::{CONTENT_LENGTH, HeaderMap, HeaderName, HeaderValue, TRANSFER_ENCODING};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Response headers that describe the original encoding rather than the content
+const UNRECORDED_HEADERS: &[HeaderName] = &[CONTENT_LENGTH, TRANSFER_ENCODING];
+
+/// Recorded request/response pairs, stored as JSON.
+///
+/// Requests are keyed by method and path (including the query string), so a
+/// cassette replays against any base URL. Bodies that are valid JSON are stored
+/// as JSON values, anything else as a string.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+impl Cassette {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read cassette '{}'", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Invalid cassette '{}'", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content + "\n")
+            .with_context(|| format!("Failed to write cassette '{}'", path.display()))
+    }
+}
+
+/// Wraps another transport and saves every exchange to a cassette file.
+///
+/// API keys sent in `Authorization` / `X-Honeycomb-Team` are never written:
+/// those headers are dropped and any occurrence of the key values in bodies or
+/// response headers is replaced with a placeholder. JSON bodies also get the
+/// same field redaction as logging, so secrets a response hands out (such as a
+/// newly created API key) stay out of the file.
+#[derive(Debug)]
+pub struct RecordingTransport {
+    inner: Arc<dyn Transport>,
+    path: PathBuf,
+    cassette: Mutex<Cassette>,
+}
+
+impl RecordingTransport {
+    /// Record into `path`, replacing any existing cassette there
+    pub fn new(inner: Arc<dyn Transport>, path: impl Into<PathBuf>) -> Self {
+        Self {
+            inner,
+            path: path.into(),
+            cassette: Mutex::new(Cassette::default()),
+        }
+    }
+}
+
+impl Transport for RecordingTransport {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let secrets = request_secrets(&request.headers);
+            let recorded_request = RecordedRequest {
+                method: request.method.to_string(),
+                path: request.path_and_query(),
+                body: request
+                    .body
+                    .as_deref()
+                    .map(|body| decode_request_body(body, &request.headers))
+                    .map(|text| recorded_body(&text, &secrets)),
+            };
+
+            let response = self.inner.send(request).await?;
+
+            let headers = response
+                .headers
+                .iter()
+                .filter(|(name, _)| {
+                    !is_sensitive_header(name.as_str()) && !UNRECORDED_HEADERS.contains(name)
+                })
+                .map(|(name, value)| {
+                    let value = value.to_str().unwrap_or_default();
+                    (name.to_string(), scrub(value, &secrets))
+                })
+                .collect();
+            let body =
+                (!response.body.is_empty()).then(|| recorded_body(&response.text(), &secrets));
+            let interaction = Interaction {
+                request: recorded_request,
+                response: RecordedResponse {
+                    status: response.status.as_u16(),
+                    headers,
+                    body,
+                },
+            };
+
+            let mut cassette = self
+                .cassette
+                .lock()
+                .map_err(|_| anyhow::anyhow!("Cassette lock poisoned"))?;
+            cassette.interactions.push(interaction);
+            cassette.save(&self.path)?;
+
+            Ok(response)
+        })
+    }
+}
+
+/// Serves responses from a cassette without touching the network.
+///
+/// Each request is answered by the first unused interaction with the same
+/// method and path; once those are used up, the last match is repeated.
+#[derive(Debug)]
+pub struct ReplayTransport {
+    interactions: Mutex<Vec<(Interaction, bool)>>,
+}
+
+impl ReplayTransport {
+    pub fn new(cassette: Cassette) -> Self {
+        Self {
+            interactions: Mutex::new(
+                cassette
+                    .interactions
+                    .into_iter()
+                    .map(|interaction| (interaction, false))
+                    .collect(),
+            ),
+        }
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self> {
+        Ok(Self::new(Cassette::load(path)?))
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let method = request.method.to_string();
+            let path = request.path_and_query();
+
+            let mut interactions = self
+                .interactions
+                .lock()
+                .map_err(|_| anyhow::anyhow!("Cassette lock poisoned"))?;
+            let matches = |(interaction, _): &(Interaction, bool)| {
+                interaction.request.method == method && interaction.request.path == path
+            };
+            let index = interactions
+                .iter()
+                .position(|entry| matches(entry) && !entry.1)
+                .or_else(|| interactions.iter().rposition(matches))
+                .ok_or_else(|| {
+                    anyhow::anyhow!("No recorded response for {} {} in cassette", method, path)
+                })?;
+
+            let (interaction, used) = &mut interactions[index];
+            *used = true;
+            replay_response(&interaction.response)
+        })
+    }
+}
+
+fn replay_response(recorded: &RecordedResponse) -> Result<HttpResponse> {
+    let mut headers = HeaderMap::new();
+    for (name, value) in &recorded.headers {
+        headers.insert(
+            HeaderName::from_bytes(name.as_bytes())?,
+            HeaderValue::from_str(value)?,
+        );
+    }
+    let body = match &recorded.body {
+        Some(Value::String(text)) => text.clone().into_bytes(),
+        Some(json) => serde_json::to_vec(json)?,
+        None => Vec::new(),
+    };
+
+    Ok(HttpResponse {
+        status: StatusCode::from_u16(recorded.status)?,
+        headers,
+        body,
+    })
+}
+
+/// Key values carried by the request's authentication headers
+fn request_secrets(headers: &HeaderMap) -> Vec<String> {
+    let mut secrets = Vec::new();
+    for (name, value) in headers {
+        if !is_sensitive_header(name.as_str()) {
+            continue;
+        }
+        let value = value.to_str().unwrap_or_default();
+        let key = value
+            .strip_prefix("Bearer ")
+            .unwrap_or(value)
+            .trim()
+            .to_string();
+        // Management keys are `id:secret`; scrub the secret half on its own too
+        if let Some((_, secret)) = key.split_once(':') {
+            secrets.push(secret.to_string());
+        }
+        secrets.push(key);
+    }
+    secrets.retain(|s| !s.is_empty());
+    // Longest first, so a full key is replaced before its secret half
+    secrets.sort_by_key(|s| std::cmp::Reverse(s.len()));
+    secrets
+}
+
+fn scrub(text: &str, secrets: &[String]) -> String {
+    secrets.iter().fold(text.to_string(), |text, secret| {
+        text.replace(secret, REDACTED)
+    })
+}
+
+fn decode_request_body(body: &[u8], headers: &HeaderMap) -> String {
+    let gzipped = headers.get("content-encoding").is_some_and(|v| v == "gzip");
+    let mut decoded = String::new();
+    if gzipped && GzDecoder::new(body).read_to_string(&mut decoded).is_ok() {
+        return decoded;
+    }
+    String::from_utf8_lossy(body).into_owned()
+}
+
+/// Body as stored in a cassette: key values scrubbed, credential fields redacted
+fn recorded_body(text: &str, secrets: &[String]) -> Value {
+    redact_json(&body_value(&scrub(text, secrets)))
+}
+
+fn body_value(text: &str) -> Value {
+    serde_json::from_str(text).unwrap_or_else(|_| Value::String(text.to_string()))
+}
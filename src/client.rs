@@ -1,45 +1,382 @@
-use crate::errors;
+use crate::cache::ResponseCache;
+use crate::cassette::RecordingTransport;
+use crate::dry_run::{self, DryRunFormat};
+use crate::errors::{self, ApiaryError};
+use crate::logging;
+use crate::rate_limit::{ApiLimiters, RateLimitConfig};
+use crate::retry::{self, RetryConfig};
+use crate::transport::{self, HttpRequest, HttpResponse, ReqwestTransport, Transport};
 use anyhow::{Context, Result};
-use reqwest::{Client, Method, Response};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use reqwest::header::{CONTENT_ENCODING, CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Certificate, Client, Identity, Method, Proxy, Url};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+use tracing::{Level, debug, info, warn};
+
+/// Default Honeycomb API base URL
+pub const DEFAULT_API_URL: &str = "https://api.honeycomb.io";
+
+/// Environment variable that permits plain HTTP base URLs (used by the test suite)
+pub const ALLOW_INSECURE_URLS_ENV: &str = "ALLOW_INSECURE_HONEYCOMB_TEST_URLS";
+
+/// Prefix of environment variables holding the configuration key for one
+/// environment, e.g. `HONEYCOMB_CONFIGURATION_API_KEY_PROD` for `prod`
+pub const ENVIRONMENT_KEY_ENV_PREFIX: &str = "HONEYCOMB_CONFIGURATION_API_KEY_";
+
+/// Environment variable checked for the configuration key of `environment`
+pub fn environment_key_var(environment: &str) -> String {
+    let suffix: String = environment
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{}{}", ENVIRONMENT_KEY_ENV_PREFIX, suffix)
+}
+
+/// User-Agent sent when none is configured
+pub fn default_user_agent() -> String {
+    format!("apiary/{}", env!("CARGO_PKG_VERSION"))
+}
+
+/// Builder for [`HoneycombClient`] with transport settings such as timeouts,
+/// proxies and TLS material. Invalid settings are reported by [`build`](Self::build)
+/// as [`ApiaryError::ConfigError`] rather than panicking.
+#[derive(Debug, Default, Clone)]
+pub struct HoneycombClientBuilder {
+    management_key: Option<String>,
+    config_key: Option<String>,
+    environment_keys: BTreeMap<String, String>,
+    base_url: Option<String>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxy: Option<String>,
+    ca_bundle: Option<PathBuf>,
+    client_cert: Option<PathBuf>,
+    client_key: Option<PathBuf>,
+    user_agent: Option<String>,
+    retry: RetryConfig,
+    config_rate_limit: Option<RateLimitConfig>,
+    management_rate_limit: Option<RateLimitConfig>,
+    dry_run: Option<DryRunFormat>,
+    cache: Option<ResponseCache>,
+    transport: Option<Arc<dyn Transport>>,
+    record_cassette: Option<PathBuf>,
+}
+
+impl HoneycombClientBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Management key (`id:secret`) used for v2 endpoints
+    pub fn management_key(mut self, key: Option<String>) -> Self {
+        self.management_key = key;
+        self
+    }
+
+    /// Configuration key used for v1 endpoints
+    pub fn config_key(mut self, key: Option<String>) -> Self {
+        self.config_key = key;
+        self
+    }
+
+    /// Configuration keys for specific environments, by environment slug
+    pub fn environment_keys(mut self, keys: BTreeMap<String, String>) -> Self {
+        self.environment_keys = keys;
+        self
+    }
+
+    /// API base URL, defaults to [`DEFAULT_API_URL`]
+    pub fn base_url(mut self, url: Option<String>) -> Self {
+        self.base_url = url;
+        self
+    }
+
+    /// Total time allowed for a single request, including reading the body
+    pub fn timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Time allowed for establishing a connection
+    pub fn connect_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Proxy URL used for all requests (e.g. `http://proxy.internal:3128`)
+    pub fn proxy(mut self, proxy: Option<String>) -> Self {
+        self.proxy = proxy;
+        self
+    }
+
+    /// PEM file with additional root certificates to trust
+    pub fn ca_bundle(mut self, path: Option<PathBuf>) -> Self {
+        self.ca_bundle = path;
+        self
+    }
+
+    /// PEM client certificate and PKCS#8 private key for mutual TLS
+    pub fn client_identity(mut self, cert: Option<PathBuf>, key: Option<PathBuf>) -> Self {
+        self.client_cert = cert;
+        self.client_key = key;
+        self
+    }
+
+    /// User-Agent header value, defaults to [`default_user_agent`]
+    pub fn user_agent(mut self, user_agent: Option<String>) -> Self {
+        self.user_agent = user_agent;
+        self
+    }
+
+    /// Retry policy used for transient failures
+    pub fn retry_config(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Client-side rate and concurrency limits for v1 configuration API requests
+    pub fn config_rate_limit(mut self, limit: RateLimitConfig) -> Self {
+        self.config_rate_limit = Some(limit);
+        self
+    }
+
+    /// Client-side rate and concurrency limits for v2 management API requests
+    pub fn management_rate_limit(mut self, limit: RateLimitConfig) -> Self {
+        self.management_rate_limit = Some(limit);
+        self
+    }
+
+    /// Render mutating requests instead of sending them
+    pub fn dry_run(mut self, format: Option<DryRunFormat>) -> Self {
+        self.dry_run = format;
+        self
+    }
+
+    /// Send requests through a custom transport instead of reqwest.
+    /// The timeout, proxy and TLS settings only apply to the default transport.
+    pub fn transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Serve GET requests from an on-disk cache while entries are fresh
+    pub fn cache(mut self, cache: Option<ResponseCache>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Save every request/response pair to a cassette file (keys scrubbed)
+    pub fn record_cassette(mut self, path: Option<PathBuf>) -> Self {
+        self.record_cassette = path;
+        self
+    }
+
+    /// reqwest client for the default transport, configured from the builder
+    fn reqwest_client(&self) -> std::result::Result<Client, ApiaryError> {
+        let mut builder = Client::builder()
+            .user_agent(self.user_agent.clone().unwrap_or_else(default_user_agent));
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+
+        if let Some(proxy) = &self.proxy {
+            let proxy = Proxy::all(proxy).map_err(|e| {
+                ApiaryError::ConfigError(format!("Invalid proxy URL '{}': {}", proxy, e))
+            })?;
+            builder = builder.proxy(proxy);
+        }
+
+        if let Some(path) = &self.ca_bundle {
+            let pem = read_pem(path, "CA bundle")?;
+            let certs = Certificate::from_pem_bundle(&pem).map_err(|e| {
+                ApiaryError::ConfigError(format!("Invalid CA bundle '{}': {}", path.display(), e))
+            })?;
+            if certs.is_empty() {
+                return Err(ApiaryError::ConfigError(format!(
+                    "CA bundle '{}' contains no certificates",
+                    path.display()
+                )));
+            }
+            for cert in certs {
+                builder = builder.add_root_certificate(cert);
+            }
+        }
+
+        match (&self.client_cert, &self.client_key) {
+            (Some(cert_path), Some(key_path)) => {
+                let cert = read_pem(cert_path, "client certificate")?;
+                let key = read_pem(key_path, "client key")?;
+                let identity = Identity::from_pkcs8_pem(&cert, &key).map_err(|e| {
+                    ApiaryError::ConfigError(format!(
+                        "Invalid client certificate '{}' or key '{}': {}",
+                        cert_path.display(),
+                        key_path.display(),
+                        e
+                    ))
+                })?;
+                builder = builder.identity(identity);
+            }
+            (None, None) => {}
+            _ => {
+                return Err(ApiaryError::ConfigError(
+                    "A client certificate and client key must be provided together".to_string(),
+                ));
+            }
+        }
+
+        builder.build().map_err(|e| {
+            ApiaryError::ConfigError(format!("Failed to initialize HTTP client: {}", e))
+        })
+    }
+
+    pub fn build(mut self) -> std::result::Result<HoneycombClient, ApiaryError> {
+        let base_url = self
+            .base_url
+            .take()
+            .unwrap_or_else(|| DEFAULT_API_URL.to_string())
+            .trim_end_matches('/')
+            .to_string();
+
+        if base_url.starts_with("http://") {
+            // Allow HTTP only if test env var is set
+            let insecure_ok = std::env::var(ALLOW_INSECURE_URLS_ENV).is_ok_and(|v| v == "true");
+            if !insecure_ok {
+                return Err(ApiaryError::ConfigError(format!(
+                    "Insecure HTTP API URL '{}'. Only HTTPS URLs are allowed.",
+                    base_url
+                )));
+            }
+        } else if !base_url.starts_with("https://") {
+            return Err(ApiaryError::ConfigError(format!(
+                "Invalid API URL '{}'. Expected an https:// URL.",
+                base_url
+            )));
+        }
+
+        let config_limit = self
+            .config_rate_limit
+            .take()
+            .unwrap_or_else(RateLimitConfig::config_api);
+        let management_limit = self
+            .management_rate_limit
+            .take()
+            .unwrap_or_else(RateLimitConfig::management_api);
+        for limit in [&config_limit, &management_limit] {
+            limit.validate().map_err(ApiaryError::ConfigError)?;
+        }
+
+        let transport = match self.transport.take() {
+            Some(transport) => transport,
+            None => Arc::new(ReqwestTransport::new(self.reqwest_client()?)),
+        };
+        let transport: Arc<dyn Transport> = match self.record_cassette {
+            Some(path) => Arc::new(RecordingTransport::new(transport, path)),
+            None => transport,
+        };
+
+        Ok(HoneycombClient {
+            transport,
+            management_key: self.management_key,
+            config_key: self.config_key,
+            environment_keys: self.environment_keys,
+            base_url,
+            retry: self.retry,
+            limiters: Arc::new(ApiLimiters::new(config_limit, management_limit)),
+            dry_run: self.dry_run,
+            dry_run_count: Arc::default(),
+            cache: self.cache,
+        })
+    }
+}
+
+/// Log the headers and body of an outgoing request with secrets redacted
+fn log_request_details(request: &HttpRequest) {
+    debug!(
+        "Request {} {} headers: {}",
+        request.method,
+        request.url,
+        logging::redact_headers(&request.headers)
+    );
+    if let Some(bytes) = &request.body {
+        if request.headers.contains_key(CONTENT_ENCODING) {
+            debug!("Request body: {} bytes (compressed)", bytes.len());
+        } else {
+            debug!(
+                "Request body ({} bytes): {}",
+                bytes.len(),
+                logging::redact_body(&String::from_utf8_lossy(bytes))
+            );
+        }
+    }
+}
+
+/// Header value from user or key input; the value itself is kept out of the error as it may be a key
+fn header_value(name: &str, value: &str) -> Result<HeaderValue> {
+    HeaderValue::from_str(value).map_err(|_| {
+        ApiaryError::ValidationError(format!("Invalid value for header '{}'", name)).into()
+    })
+}
+
+fn read_pem(path: &Path, what: &str) -> std::result::Result<Vec<u8>, ApiaryError> {
+    std::fs::read(path).map_err(|e| {
+        ApiaryError::ConfigError(format!(
+            "Failed to read {} '{}': {}",
+            what,
+            path.display(),
+            e
+        ))
+    })
+}
 
 #[derive(Debug, Clone)]
 pub struct HoneycombClient {
-    client: Client,
+    transport: Arc<dyn Transport>,
     management_key: Option<String>,
     config_key: Option<String>,
+    environment_keys: BTreeMap<String, String>,
     base_url: String,
+    retry: RetryConfig,
+    limiters: Arc<ApiLimiters>,
+    dry_run: Option<DryRunFormat>,
+    /// Number of requests printed instead of sent, shared by clones of this client
+    dry_run_count: Arc<AtomicUsize>,
+    cache: Option<ResponseCache>,
 }
 
 impl HoneycombClient {
+    /// Create a client with default transport settings; shorthand for
+    /// [`HoneycombClient::builder`] with only keys and a base URL
+    #[allow(dead_code)]
     pub fn new(
         management_key: Option<String>,
         config_key: Option<String>,
         base_url: Option<String>,
-    ) -> Self {
-        // Default to secure URL if not provided
-        let base_url_string = base_url.unwrap_or_else(|| "https://api.honeycomb.io".to_string());
-        // Deny HTTP URLs unless allowed for testing
-        if base_url_string.starts_with("http://") {
-            // Allow HTTP only if test env var is set
-            use std::env;
-            let insecure_ok = env::var("ALLOW_INSECURE_HONEYCOMB_TEST_URLS")
-                .unwrap_or_else(|_| "false".to_string())
-                == "true";
-            if !insecure_ok {
-                panic!(
-                    "Insecure HTTP base_url provided to HoneycombClient::new: '{}'. Only HTTPS URLs are allowed in production.",
-                    base_url_string
-                );
-            }
-        }
-        Self {
-            client: Client::new(),
-            management_key,
-            config_key,
-            base_url: base_url_string,
-        }
+    ) -> std::result::Result<Self, ApiaryError> {
+        Self::builder()
+            .management_key(management_key)
+            .config_key(config_key)
+            .base_url(base_url)
+            .build()
+    }
+
+    pub fn builder() -> HoneycombClientBuilder {
+        HoneycombClientBuilder::new()
     }
 
     /// Get the appropriate API key for debugging/info purposes
@@ -56,55 +393,192 @@ impl HoneycombClient {
         method: Method,
         path: &str,
         query_params: Option<&HashMap<String, String>>,
+        headers: Option<&HashMap<String, String>>,
         body: Option<&Value>,
-    ) -> Result<Response> {
-        let url = format!("{}{}", self.base_url, path);
+    ) -> Result<HttpResponse> {
+        let mut request = self.build_request(method, path, query_params, headers)?;
 
-        let mut request = self.client.request(method, &url);
+        // Add body for POST/PUT/PATCH requests
+        if let Some(body) = body {
+            request.body = Some(serde_json::to_vec(body)?);
+        }
+
+        self.send(request).await
+    }
+
+    /// Send a request, answering GETs from the response cache when enabled.
+    /// In dry-run mode mutating requests are printed and answered with a
+    /// placeholder response instead of being sent.
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let mutating = dry_run::is_mutating(&request.method, request.url.path());
+        if let Some(format) = self.dry_run
+            && mutating
+        {
+            // stderr, so previews never mix with a command's JSON output
+            eprintln!("{}", dry_run::render(format, &request));
+            self.dry_run_count.fetch_add(1, Ordering::Relaxed);
+            return Ok(dry_run::placeholder_response(&request));
+        }
+
+        let Some(cache) = &self.cache else {
+            return self.send_with_retry(&request).await;
+        };
+        let cacheable = ResponseCache::is_cacheable(&request);
+        if cacheable && let Some(response) = cache.get(&request) {
+            return Ok(response);
+        }
+
+        let response = self.send_with_retry(&request).await?;
+        if cacheable {
+            cache.put(&request, &response);
+        } else if ResponseCache::is_invalidated_by(&request) && response.status.is_success() {
+            cache.invalidate();
+        }
+        Ok(response)
+    }
+
+    /// Send a request, retrying 429/5xx responses and connection errors with backoff.
+    /// Every attempt waits for the client-side rate limit of its API family.
+    async fn send_with_retry(&self, request: &HttpRequest) -> Result<HttpResponse> {
+        let method = request.method.clone();
+        let url = request.url.to_string();
+
+        let limiter = self.limiters.for_path(request.url.path());
+        let started = Instant::now();
+        let retries_allowed = self.retry.allows_method(&method);
+        let mut attempt = 0;
+
+        loop {
+            if tracing::enabled!(Level::DEBUG) {
+                log_request_details(request);
+            }
+            let permit = limiter.acquire().await;
+            if !permit.waited.is_zero() {
+                debug!(
+                    "Waited {}ms for the client-side rate limit",
+                    permit.waited.as_millis()
+                );
+            }
+            let attempt_started = Instant::now();
+            let result = self.transport.send(request.clone()).await;
+            let elapsed_ms = attempt_started.elapsed().as_millis();
+            drop(permit);
+
+            match &result {
+                Ok(response) => info!(
+                    "{} {} -> {} in {}ms ({} bytes)",
+                    method,
+                    url,
+                    response.status,
+                    elapsed_ms,
+                    response.body.len()
+                ),
+                Err(e) => warn!("{} {} failed after {}ms: {:#}", method, url, elapsed_ms, e),
+            }
+
+            let can_retry = retries_allowed && attempt < self.retry.max_retries;
+            let delay = match &result {
+                Ok(response) if can_retry && retry::is_retryable_status(response.status) => {
+                    retry::parse_retry_after(&response.headers)
+                        .unwrap_or_else(|| self.retry.backoff(attempt))
+                }
+                Err(e) if can_retry && transport::is_transient_error(e) => {
+                    self.retry.backoff(attempt)
+                }
+                _ => {
+                    return result.with_context(|| format!("Failed to send request to {}", url));
+                }
+            };
+
+            // Stop retrying if waiting would exceed the overall retry budget
+            if started.elapsed() + delay > self.retry.retry_timeout {
+                return result.with_context(|| format!("Failed to send request to {}", url));
+            }
+
+            drop(result);
+            info!(
+                "Retrying {} {} in {}ms (retry {} of {})",
+                method,
+                url,
+                delay.as_millis(),
+                attempt + 1,
+                self.retry.max_retries
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Build an authenticated request without a body
+    fn build_request(
+        &self,
+        method: Method,
+        path: &str,
+        query_params: Option<&HashMap<String, String>>,
+        headers: Option<&HashMap<String, String>>,
+    ) -> Result<HttpRequest> {
+        let mut url = Url::parse(&format!("{}{}", self.base_url, path))
+            .with_context(|| format!("Invalid request URL {}{}", self.base_url, path))?;
+        let mut request_headers = HeaderMap::new();
 
         // Use appropriate authentication based on endpoint
         if self.is_v2_endpoint(path) {
             // v2 endpoints use Management Key with Bearer token
             if let Some(management_key) = &self.management_key {
-                request = request.header("Authorization", format!("Bearer {}", management_key));
+                request_headers.insert(
+                    reqwest::header::AUTHORIZATION,
+                    header_value("Authorization", &format!("Bearer {}", management_key))?,
+                );
             } else {
-                return Err(anyhow::anyhow!(
+                return Err(ApiaryError::AuthenticationRequired(format!(
                     "{} Endpoint: '{}'",
                     errors::messages::MANAGEMENT_KEY_REQUIRED,
                     path
-                ));
+                ))
+                .into());
             }
         } else {
             // v1 endpoints use Configuration Key with X-Honeycomb-Team header
             if let Some(config_key) = &self.config_key {
-                request = request.header("X-Honeycomb-Team", config_key);
+                request_headers.insert(
+                    "X-Honeycomb-Team",
+                    header_value("X-Honeycomb-Team", config_key)?,
+                );
             } else {
-                return Err(anyhow::anyhow!(
+                return Err(ApiaryError::AuthenticationRequired(format!(
                     "{} Endpoint: '{}'",
                     errors::messages::CONFIG_KEY_REQUIRED,
                     path
-                ));
+                ))
+                .into());
             }
         }
 
-        request = request.header("Content-Type", "application/json");
+        request_headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
 
-        // Add query parameters
-        if let Some(params) = query_params {
-            for (key, value) in params {
-                request = request.query(&[(key, value)]);
+        // Add any extra headers (e.g. event metadata for ingest endpoints)
+        if let Some(headers) = headers {
+            for (key, value) in headers {
+                let name = HeaderName::from_bytes(key.as_bytes()).map_err(|_| {
+                    ApiaryError::ValidationError(format!("Invalid header name '{}'", key))
+                })?;
+                request_headers.insert(name, header_value(key, value)?);
             }
         }
 
-        // Add body for POST/PUT/PATCH requests
-        if let Some(body) = body {
-            request = request.json(body);
+        // Add query parameters, sorted so identical requests produce identical URLs
+        if let Some(params) = query_params.filter(|p| !p.is_empty()) {
+            let mut params: Vec<_> = params.iter().collect();
+            params.sort();
+            url.query_pairs_mut().extend_pairs(params);
         }
 
-        request
-            .send()
-            .await
-            .with_context(|| format!("Failed to send request to {}", url))
+        Ok(HttpRequest {
+            method,
+            url,
+            headers: request_headers,
+            body: None,
+        })
     }
 
     pub async fn get(
@@ -112,65 +586,143 @@ impl HoneycombClient {
         path: &str,
         query_params: Option<&HashMap<String, String>>,
     ) -> Result<Value> {
-        let response = self.request(Method::GET, path, query_params, None).await?;
-        self.handle_response(response, path).await
+        let response = self
+            .request(Method::GET, path, query_params, None, None)
+            .await?;
+        self.handle_response(response, path)
     }
 
     pub async fn post(&self, path: &str, body: &Value) -> Result<Value> {
-        let response = self.request(Method::POST, path, None, Some(body)).await?;
-        self.handle_response(response, path).await
+        let response = self
+            .request(Method::POST, path, None, None, Some(body))
+            .await?;
+        self.handle_response(response, path)
+    }
+
+    pub async fn post_with_headers(
+        &self,
+        path: &str,
+        body: &Value,
+        headers: &HashMap<String, String>,
+    ) -> Result<Value> {
+        let response = self
+            .request(Method::POST, path, None, Some(headers), Some(body))
+            .await?;
+        self.handle_response(response, path)
+    }
+
+    /// POST a JSON body compressed with gzip (used by the batch ingest endpoint)
+    pub async fn post_gzip(&self, path: &str, body: &Value) -> Result<Value> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        serde_json::to_writer(&mut encoder, body)?;
+
+        let mut request = self.build_request(Method::POST, path, None, None)?;
+        request
+            .headers
+            .insert(CONTENT_ENCODING, HeaderValue::from_static("gzip"));
+        request.body = Some(encoder.finish()?);
+
+        let response = self.send(request).await?;
+        self.handle_response(response, path)
     }
 
     pub async fn put(&self, path: &str, body: &Value) -> Result<Value> {
-        let response = self.request(Method::PUT, path, None, Some(body)).await?;
-        self.handle_response(response, path).await
+        let response = self
+            .request(Method::PUT, path, None, None, Some(body))
+            .await?;
+        self.handle_response(response, path)
     }
 
     pub async fn patch(&self, path: &str, body: &Value) -> Result<Value> {
-        let response = self.request(Method::PATCH, path, None, Some(body)).await?;
-        self.handle_response(response, path).await
+        let response = self
+            .request(Method::PATCH, path, None, None, Some(body))
+            .await?;
+        self.handle_response(response, path)
     }
 
     pub async fn delete(&self, path: &str) -> Result<()> {
-        let response = self.request(Method::DELETE, path, None, None).await?;
-        if response.status().is_success() {
-            Ok(())
+        let response = self.request(Method::DELETE, path, None, None, None).await?;
+        self.response_text(response, path)?;
+        Ok(())
+    }
+
+    /// Build a typed error from a non-success response body
+    fn api_error(status: u16, text: String, url: &str) -> ApiaryError {
+        let body = serde_json::from_str(&text).unwrap_or(Value::String(text));
+        errors::parse_api_error(status, &body, url)
+    }
+
+    fn handle_response(&self, response: HttpResponse, path: &str) -> Result<Value> {
+        let text = self.response_text(response, path)?;
+
+        if text.is_empty() {
+            Ok(Value::Null)
         } else {
-            let status = response.status();
-            let text = response.text().await.unwrap_or_default();
-            let url = format!("{}{}", self.base_url, path);
-            anyhow::bail!(
-                "Request failed with status {}: {} (URL: {})",
-                status,
-                text,
-                url
-            );
+            serde_json::from_str(&text).with_context(|| {
+                format!(
+                    "Failed to parse JSON response from {}{}: {}",
+                    self.base_url, path, text
+                )
+            })
         }
     }
 
-    async fn handle_response(&self, response: Response, path: &str) -> Result<Value> {
-        let status = response.status();
-        let text = response.text().await?;
-        let url = format!("{}{}", self.base_url, path);
+    /// Read a response body, turning non-success statuses into typed API errors
+    pub fn response_text(&self, response: HttpResponse, path: &str) -> Result<String> {
+        let status = response.status;
+        let text = response.text();
+        debug!(
+            "Response body from {} ({} bytes): {}",
+            path,
+            text.len(),
+            logging::redact_body(&text)
+        );
 
         if status.is_success() {
-            if text.is_empty() {
-                Ok(Value::Null)
-            } else {
-                serde_json::from_str(&text).with_context(|| {
-                    format!("Failed to parse JSON response from {}: {}", url, text)
-                })
-            }
+            Ok(text)
         } else {
-            anyhow::bail!(
-                "Request failed with status {}: {} (URL: {})",
-                status,
-                text,
-                url
-            );
+            let url = format!("{}{}", self.base_url, path);
+            Err(Self::api_error(status.as_u16(), text, &url).into())
         }
     }
 
+    /// Configuration key for an environment: one passed to the builder, else
+    /// the one in the environment's `HONEYCOMB_CONFIGURATION_API_KEY_<SLUG>` variable
+    pub fn environment_key(&self, environment: &str) -> Option<String> {
+        self.environment_keys
+            .get(environment)
+            .cloned()
+            .or_else(|| std::env::var(environment_key_var(environment)).ok())
+            .filter(|key| !key.is_empty())
+    }
+
+    /// Copy of this client using a different configuration key (rate limits,
+    /// cache and transport are shared)
+    pub fn with_config_key(&self, key: String) -> Self {
+        Self {
+            config_key: Some(key),
+            ..self.clone()
+        }
+    }
+
+    /// Copy of this client talking to a different API host, e.g. another region
+    pub fn with_base_url(&self, base_url: String) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            ..self.clone()
+        }
+    }
+
+    /// Whether mutating requests are printed instead of sent
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run.is_some()
+    }
+
+    /// Number of requests printed instead of sent so far
+    pub fn dry_run_count(&self) -> usize {
+        self.dry_run_count.load(Ordering::Relaxed)
+    }
+
     /// Check if an endpoint is a v2 endpoint (uses Management Key)
     pub fn is_v2_endpoint(&self, path: &str) -> bool {
         path.starts_with("/2/")
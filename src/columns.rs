@@ -1,7 +1,7 @@
 use crate::client::HoneycombClient;
 use crate::common::{
-    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, pretty_print_json,
-    read_json_file,
+    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, environment_client,
+    pretty_print_json, read_json_file,
 };
 use anyhow::Result;
 use clap::Subcommand;
@@ -16,7 +16,7 @@ pub enum ColumnCommands {
         #[arg(short, long)]
         dataset: String,
         /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
-        #[arg(short, long, env = "HONEYCOMB_ENVIRONMENT")]
+        #[arg(short, long)]
         environment: Option<String>,
         /// Output format
         #[arg(short, long, default_value = DEFAULT_TABLE_FORMAT)]
@@ -27,6 +27,9 @@ pub enum ColumnCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Column ID
         #[arg(short, long)]
         id: String,
@@ -39,6 +42,9 @@ pub enum ColumnCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Column data (JSON file path or inline JSON)
         #[arg(long)]
         data: String,
@@ -51,6 +57,9 @@ pub enum ColumnCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Column ID
         #[arg(short, long)]
         id: String,
@@ -66,6 +75,9 @@ pub enum ColumnCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Column ID
         #[arg(short, long)]
         id: String,
@@ -85,31 +97,44 @@ pub struct Column {
 }
 
 impl ColumnCommands {
-    pub async fn execute(&self, client: &HoneycombClient, _context: &CommandContext) -> Result<()> {
+    fn environment(&self) -> Option<&str> {
+        match self {
+            ColumnCommands::List { environment, .. }
+            | ColumnCommands::Get { environment, .. }
+            | ColumnCommands::Create { environment, .. }
+            | ColumnCommands::Update { environment, .. }
+            | ColumnCommands::Delete { environment, .. } => environment.as_deref(),
+        }
+    }
+
+    pub async fn execute(&self, client: &HoneycombClient, context: &CommandContext) -> Result<()> {
+        let client = &environment_client(client, context, self.environment()).await?;
+
         match self {
             ColumnCommands::List {
-                dataset,
-                environment,
-                format,
-            } => list_columns(client, dataset, environment.as_deref(), format).await,
+                dataset, format, ..
+            } => list_columns(client, dataset, format).await,
 
             ColumnCommands::Get {
                 dataset,
                 id,
                 format,
+                ..
             } => get_column(client, dataset, id, format).await,
             ColumnCommands::Create {
                 dataset,
                 data,
                 format,
+                ..
             } => create_column(client, dataset, data, format).await,
             ColumnCommands::Update {
                 dataset,
                 id,
                 data,
                 format,
+                ..
             } => update_column(client, dataset, id, data, format).await,
-            ColumnCommands::Delete { dataset, id } => delete_column(client, dataset, id).await,
+            ColumnCommands::Delete { dataset, id, .. } => delete_column(client, dataset, id).await,
         }
     }
 }
@@ -117,36 +142,10 @@ impl ColumnCommands {
 async fn list_columns(
     client: &HoneycombClient,
     dataset: &str,
-    environment: Option<&str>,
     format: &OutputFormat,
 ) -> Result<()> {
-    use crate::common::require_valid_environment;
-    use std::collections::HashMap;
-
-    // If environment is provided, validate it exists
-    if let Some(env) = environment {
-        let team = std::env::var("HONEYCOMB_TEAM").unwrap_or_else(|_| "default".to_string());
-        require_valid_environment(client, &team, env).await?;
-    }
-
     let path = format!("/1/columns/{}", dataset);
-
-    // Add environment as query parameter if provided
-    let mut query_params = HashMap::new();
-    if let Some(env) = environment {
-        query_params.insert("environment".to_string(), env.to_string());
-    }
-
-    let response = client
-        .get(
-            &path,
-            if query_params.is_empty() {
-                None
-            } else {
-                Some(&query_params)
-            },
-        )
-        .await?;
+    let response = client.get(&path, None).await?;
 
     match format {
         OutputFormat::Json => {
@@ -218,6 +217,9 @@ async fn create_column(
 
     let path = format!("/1/columns/{}", dataset);
     let response = client.post(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -246,6 +248,9 @@ async fn update_column(
 
     let path = format!("/1/columns/{}/{}", dataset, id);
     let response = client.put(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -262,6 +267,9 @@ async fn update_column(
 async fn delete_column(client: &HoneycombClient, dataset: &str, id: &str) -> Result<()> {
     let path = format!("/1/columns/{}/{}", dataset, id);
     client.delete(&path).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     println!(
         "Column '{}' in dataset '{}' deleted successfully",
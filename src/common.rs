@@ -1,7 +1,10 @@
-use crate::client::HoneycombClient;
+use crate::auth::V1AuthResponse;
+use crate::client::{self, HoneycombClient};
 use crate::errors;
-use anyhow::Result;
+use crate::pagination::{PageArgs, Pages};
+use anyhow::{Context, Result};
 use serde_json::Value;
+use std::collections::HashMap;
 
 // Constants for consistency
 pub const DEFAULT_TABLE_FORMAT: &str = "table";
@@ -10,7 +13,24 @@ pub const DEFAULT_PRETTY_FORMAT: &str = "pretty";
 // Context for command execution
 #[derive(Debug, Clone)]
 pub struct CommandContext {
+    /// Global `--team`, already falling back to the active profile and then `HONEYCOMB_TEAM`
     pub team: Option<String>,
+    /// Global `--environment`, with the same fallbacks via `HONEYCOMB_ENVIRONMENT`
+    pub environment: Option<String>,
+}
+
+impl CommandContext {
+    /// Team for a command: its own `--team` flag, else the global one
+    pub fn team<'a>(&'a self, flag: Option<&'a str>) -> Result<&'a str> {
+        flag.or(self.team.as_deref()).ok_or_else(|| {
+            errors::ApiaryError::ConfigError(errors::messages::TEAM_REQUIRED.into()).into()
+        })
+    }
+
+    /// Environment for a command: its own `--environment` flag, else the global one
+    pub fn environment<'a>(&'a self, flag: Option<&'a str>) -> Option<&'a str> {
+        flag.or(self.environment.as_deref())
+    }
 }
 
 // Common utility functions
@@ -32,25 +52,23 @@ pub async fn validate_environment(
     }
 
     let path = format!("/2/teams/{}/environments", team);
-    let response = client.get(&path, None).await?;
-
-    if let Value::Object(obj) = response
-        && let Some(Value::Array(envs)) = obj.get("data")
-    {
-        for env in envs {
-            if let Value::Object(env_obj) = env
-                && let Some(Value::Object(attrs)) = env_obj.get("attributes")
+    let envs = Pages::new(client, &path, HashMap::new(), &PageArgs::all())
+        .collect()
+        .await?;
+
+    for env in envs {
+        if let Value::Object(env_obj) = env
+            && let Some(Value::Object(attrs)) = env_obj.get("attributes")
+        {
+            if let Some(Value::String(slug)) = attrs.get("slug")
+                && slug == environment
+            {
+                return Ok(true);
+            }
+            if let Some(Value::String(name)) = attrs.get("name")
+                && name == environment
             {
-                if let Some(Value::String(slug)) = attrs.get("slug")
-                    && slug == environment
-                {
-                    return Ok(true);
-                }
-                if let Some(Value::String(name)) = attrs.get("name")
-                    && name == environment
-                {
-                    return Ok(true);
-                }
+                return Ok(true);
             }
         }
     }
@@ -65,14 +83,53 @@ pub async fn require_valid_environment(
     environment: &str,
 ) -> Result<()> {
     if !validate_environment(client, team, environment).await? {
-        anyhow::bail!(
-            "{}",
-            errors::messages::environment_not_found(environment, team)
-        );
+        return Err(anyhow::Error::new(errors::ApiaryError::NotFound {
+            resource: "Environment".to_string(),
+            identifier: environment.to_string(),
+        })
+        .context(errors::messages::environment_not_found(environment, team)));
     }
     Ok(())
 }
 
+/// Client for v1 requests against `environment` (or the global environment).
+///
+/// Configuration keys are scoped to a single environment, so the environment
+/// is selected by key rather than by query parameter: a key mapped to the
+/// environment is used when there is one, otherwise the default key has to
+/// belong to it (checked with `/1/auth`).
+pub async fn environment_client(
+    client: &HoneycombClient,
+    context: &CommandContext,
+    environment: Option<&str>,
+) -> Result<HoneycombClient> {
+    let Some(environment) = context.environment(environment) else {
+        return Ok(client.clone());
+    };
+    if let Some(key) = client.environment_key(environment) {
+        return Ok(client.with_config_key(key));
+    }
+
+    let auth: V1AuthResponse = serde_json::from_value(client.get("/1/auth", None).await?)
+        .context("Unexpected response from /1/auth")?;
+    if auth.environment.slug == environment || auth.environment.name == environment {
+        return Ok(client.clone());
+    }
+
+    // Report a mistyped environment as such when environments can be listed
+    if let Some(team) = &context.team {
+        require_valid_environment(client, team, environment).await?;
+    }
+    Err(
+        errors::ApiaryError::ConfigError(errors::messages::environment_key_mismatch(
+            &auth.environment.slug,
+            environment,
+            &client::environment_key_var(environment),
+        ))
+        .into(),
+    )
+}
+
 pub fn pretty_print_json(value: &serde_json::Value) -> anyhow::Result<String> {
     serde_json::to_string_pretty(value).map_err(Into::into)
 }
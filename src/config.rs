@@ -0,0 +1,430 @@
+use crate::client::environment_key_var;
+use crate::dry_run::shell_quote;
+use crate::errors::ApiaryError;
+use crate::region::Region;
+use anyhow::{Context, Result};
+use clap::{Subcommand, ValueEnum};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Settings a profile can hold, in display order
+pub const PROFILE_KEYS: &[&str] = &[
+    "region",
+    "api_endpoint",
+    "api_url",
+    "team",
+    "environment",
+    "config_key_env",
+    "management_key_id_env",
+    "management_key_env",
+    "config_key_command",
+    "management_key_command",
+    "environment_keys.<slug>",
+];
+
+/// Prefix of the settings mapping an environment slug to its key variable
+const ENVIRONMENT_KEYS_PREFIX: &str = "environment_keys.";
+
+#[derive(Subcommand)]
+pub enum ConfigCommands {
+    /// List profiles (the active one is marked with *)
+    List,
+    /// Print a setting of the active profile
+    Get {
+        /// Setting name (region, api_endpoint, api_url, team, environment, config_key_env,
+        /// management_key_id_env, management_key_env, config_key_command,
+        /// management_key_command, environment_keys.<slug>)
+        key: String,
+    },
+    /// Change a setting of the active profile, creating the profile if needed
+    Set {
+        /// Setting name
+        key: String,
+        /// New value (an empty string removes the setting)
+        value: String,
+    },
+    /// Make a profile the default for future invocations
+    Use {
+        /// Profile name
+        name: String,
+    },
+    /// Print the name of the active profile
+    Current,
+    /// Print shell commands that export the active profile's settings
+    Env,
+}
+
+/// `$XDG_CONFIG_HOME/apiary/config.toml`, falling back to `~/.config/apiary/config.toml`
+pub fn default_config_path() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("apiary").join("config.toml"))
+}
+
+/// Contents of the config file
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ConfigFile {
+    /// Profile used when neither `--profile` nor `APIARY_PROFILE` is given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_profile: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+/// Named set of defaults for the global options.
+///
+/// Keys are never stored in the file; a profile names the environment
+/// variables that hold them, or commands that print them, instead.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    /// Region (`us` or `eu`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_endpoint: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<String>,
+    /// Environment variable holding the configuration key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_key_env: Option<String>,
+    /// Environment variable holding the management key ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub management_key_id_env: Option<String>,
+    /// Environment variable holding the management key secret
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub management_key_env: Option<String>,
+    /// Command that prints the configuration key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_key_command: Option<String>,
+    /// Command that prints the management key secret (or `id:secret`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub management_key_command: Option<String>,
+    /// Environment variables holding configuration keys for specific
+    /// environments, by environment slug
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub environment_keys: BTreeMap<String, String>,
+}
+
+impl ConfigFile {
+    /// Read the config file; a missing file is treated as empty
+    pub fn load(path: &Path) -> Result<Self, ApiaryError> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => {
+                return Err(ApiaryError::ConfigError(format!(
+                    "Failed to read config file '{}': {}",
+                    path.display(),
+                    e
+                )));
+            }
+        };
+        toml::from_str(&content).map_err(|e| {
+            ApiaryError::ConfigError(format!("Invalid config file '{}': {}", path.display(), e))
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create '{}'", dir.display()))?;
+        }
+        let content = toml::to_string_pretty(self)?;
+        std::fs::write(path, content)
+            .with_context(|| format!("Failed to write config file '{}'", path.display()))
+    }
+
+    /// Name of the active profile: the selected one, else the file's current profile
+    pub fn active_name<'a>(&'a self, selected: Option<&'a str>) -> Option<&'a str> {
+        selected.or(self.current_profile.as_deref())
+    }
+
+    /// The active profile, failing if a profile is selected but not defined
+    pub fn active_profile(
+        &self,
+        selected: Option<&str>,
+        path: &Path,
+    ) -> Result<Option<&Profile>, ApiaryError> {
+        match self.active_name(selected) {
+            Some(name) => self.profiles.get(name).map(Some).ok_or_else(|| {
+                ApiaryError::ConfigError(format!(
+                    "Profile '{}' not found in '{}'",
+                    name,
+                    path.display()
+                ))
+            }),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Profile {
+    pub fn get(&self, key: &str) -> Result<Option<&str>, ApiaryError> {
+        if let Some(slug) = key.strip_prefix(ENVIRONMENT_KEYS_PREFIX) {
+            return Ok(self.environment_keys.get(slug).map(String::as_str));
+        }
+        let value = match key {
+            "region" => &self.region,
+            "api_endpoint" => &self.api_endpoint,
+            "api_url" => &self.api_url,
+            "team" => &self.team,
+            "environment" => &self.environment,
+            "config_key_env" => &self.config_key_env,
+            "management_key_id_env" => &self.management_key_id_env,
+            "management_key_env" => &self.management_key_env,
+            "config_key_command" => &self.config_key_command,
+            "management_key_command" => &self.management_key_command,
+            _ => return Err(unknown_key(key)),
+        };
+        Ok(value.as_deref())
+    }
+
+    pub fn set(&mut self, key: &str, value: Option<String>) -> Result<(), ApiaryError> {
+        let environment_slug = key.strip_prefix(ENVIRONMENT_KEYS_PREFIX);
+        if (key.ends_with("_env") || environment_slug.is_some())
+            && let Some(name) = &value
+            && !is_env_var_name(name)
+        {
+            return Err(ApiaryError::ValidationError(format!(
+                "'{}' must name an environment variable, got '{}'",
+                key, name
+            )));
+        }
+        if key == "region"
+            && let Some(region) = &value
+            && Region::from_str(region, true).is_err()
+        {
+            return Err(ApiaryError::ValidationError(format!(
+                "Unknown region '{}'. Expected one of: {}",
+                region,
+                Region::ALL.map(|region| region.to_string()).join(", ")
+            )));
+        }
+        if let Some(slug) = environment_slug {
+            if slug.is_empty() {
+                return Err(unknown_key(key));
+            }
+            match value {
+                Some(name) => self.environment_keys.insert(slug.to_string(), name),
+                None => self.environment_keys.remove(slug),
+            };
+            return Ok(());
+        }
+        let slot = match key {
+            "region" => &mut self.region,
+            "api_endpoint" => &mut self.api_endpoint,
+            "api_url" => &mut self.api_url,
+            "team" => &mut self.team,
+            "environment" => &mut self.environment,
+            "config_key_env" => &mut self.config_key_env,
+            "management_key_id_env" => &mut self.management_key_id_env,
+            "management_key_env" => &mut self.management_key_env,
+            "config_key_command" => &mut self.config_key_command,
+            "management_key_command" => &mut self.management_key_command,
+            _ => return Err(unknown_key(key)),
+        };
+        *slot = value;
+        Ok(())
+    }
+
+    /// Settings paired with the standard environment variables they stand in for
+    fn values(&self) -> [(&'static str, Option<&str>); 7] {
+        [
+            ("HONEYCOMB_REGION", self.region.as_deref()),
+            ("HONEYCOMB_API_ENDPOINT", self.api_endpoint.as_deref()),
+            ("HONEYCOMB_API_URL", self.api_url.as_deref()),
+            ("HONEYCOMB_TEAM", self.team.as_deref()),
+            ("HONEYCOMB_ENVIRONMENT", self.environment.as_deref()),
+            (
+                "HONEYCOMB_CONFIGURATION_API_KEY_COMMAND",
+                self.config_key_command.as_deref(),
+            ),
+            (
+                "HONEYCOMB_MANAGEMENT_API_KEY_COMMAND",
+                self.management_key_command.as_deref(),
+            ),
+        ]
+    }
+
+    /// Key references paired with the standard key environment variables
+    fn key_references(&self) -> [(&'static str, Option<&str>); 3] {
+        [
+            (
+                "HONEYCOMB_CONFIGURATION_API_KEY",
+                self.config_key_env.as_deref(),
+            ),
+            (
+                "HONEYCOMB_MANAGEMENT_API_KEY_ID",
+                self.management_key_id_env.as_deref(),
+            ),
+            (
+                "HONEYCOMB_MANAGEMENT_API_KEY",
+                self.management_key_env.as_deref(),
+            ),
+        ]
+    }
+
+    /// Configuration keys by environment slug, read from the variables the
+    /// profile names; unset variables are skipped
+    pub fn environment_key_values(&self) -> BTreeMap<String, String> {
+        self.environment_keys
+            .iter()
+            .filter_map(|(slug, var)| Some((slug.clone(), std::env::var(var).ok()?)))
+            .collect()
+    }
+
+    /// Make the profile's settings the defaults of the matching CLI options.
+    ///
+    /// Every option that falls back to `HONEYCOMB_TEAM`, `HONEYCOMB_ENVIRONMENT`,
+    /// `HONEYCOMB_REGION`, `HONEYCOMB_API_ENDPOINT`, `HONEYCOMB_API_URL` or one
+    /// of the key command variables uses the profile's value instead, so flags still win but stale
+    /// shell variables don't. Key options read from the environment variables
+    /// the profile names instead of the standard ones.
+    pub fn apply(&self, command: clap::Command) -> clap::Command {
+        override_args(command, &self.values(), &self.key_references())
+    }
+
+    /// `export`/`unset` lines reproducing the profile with the standard environment variables
+    pub fn shell_exports(&self) -> String {
+        let mut out = String::new();
+        for (var, value) in self.values() {
+            match value {
+                Some(value) => out.push_str(&format!("export {}={}\n", var, shell_quote(value))),
+                None => out.push_str(&format!("unset {}\n", var)),
+            }
+        }
+        // Key references expand when the output is evaluated, so keys never appear here
+        for (var, reference) in self.key_references() {
+            if let Some(reference) = reference.filter(|name| is_env_var_name(name)) {
+                out.push_str(&format!("export {}=\"${{{}}}\"\n", var, reference));
+            }
+        }
+        for (slug, reference) in &self.environment_keys {
+            if is_env_var_name(reference) {
+                out.push_str(&format!(
+                    "export {}=\"${{{}}}\"\n",
+                    environment_key_var(slug),
+                    reference
+                ));
+            }
+        }
+        out
+    }
+}
+
+fn override_args(
+    mut command: clap::Command,
+    values: &[(&str, Option<&str>)],
+    key_references: &[(&str, Option<&str>)],
+) -> clap::Command {
+    let args: Vec<(String, String)> = command
+        .get_arguments()
+        .filter_map(|arg| {
+            let env = arg.get_env()?.to_str()?;
+            Some((arg.get_id().to_string(), env.to_string()))
+        })
+        .collect();
+
+    for (id, env) in args {
+        if let Some((_, Some(value))) = values.iter().find(|(var, _)| *var == env) {
+            let value = value.to_string();
+            command = command.mut_arg(id, |arg| arg.env(None::<&str>).default_value(value));
+        } else if let Some((_, Some(name))) = key_references.iter().find(|(var, _)| *var == env) {
+            let name = name.to_string();
+            command = command.mut_arg(id, |arg| arg.env(name));
+        }
+    }
+    command.mut_subcommands(|sub| override_args(sub, values, key_references))
+}
+
+fn unknown_key(key: &str) -> ApiaryError {
+    ApiaryError::ValidationError(format!(
+        "Unknown setting '{}'. Expected one of: {}",
+        key,
+        PROFILE_KEYS.join(", ")
+    ))
+}
+
+fn is_env_var_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+impl ConfigCommands {
+    pub fn execute(&self, path: &Path, selected: Option<&str>) -> Result<()> {
+        let mut file = ConfigFile::load(path)?;
+
+        match self {
+            ConfigCommands::List => {
+                let active = file.active_name(selected);
+                for name in file.profiles.keys() {
+                    let marker = if Some(name.as_str()) == active {
+                        "*"
+                    } else {
+                        " "
+                    };
+                    println!("{} {}", marker, name);
+                }
+            }
+            ConfigCommands::Get { key } => {
+                let profile = file
+                    .active_profile(selected, path)?
+                    .ok_or_else(no_profile_selected)?;
+                if let Some(value) = profile.get(key)? {
+                    println!("{}", value);
+                }
+            }
+            ConfigCommands::Set { key, value } => {
+                let name = file
+                    .active_name(selected)
+                    .ok_or_else(no_profile_selected)?
+                    .to_string();
+                let value = Some(value.clone()).filter(|v| !v.is_empty());
+                file.profiles.entry(name).or_default().set(key, value)?;
+                file.save(path)?;
+            }
+            ConfigCommands::Use { name } => {
+                if !file.profiles.contains_key(name) {
+                    return Err(ApiaryError::ConfigError(format!(
+                        "Profile '{}' not found in '{}'",
+                        name,
+                        path.display()
+                    ))
+                    .into());
+                }
+                file.current_profile = Some(name.clone());
+                file.save(path)?;
+                println!("Switched to profile '{}'", name);
+            }
+            ConfigCommands::Current => {
+                let name = file.active_name(selected).ok_or_else(no_profile_selected)?;
+                println!("{}", name);
+            }
+            ConfigCommands::Env => {
+                let profile = file
+                    .active_profile(selected, path)?
+                    .ok_or_else(no_profile_selected)?;
+                print!("{}", profile.shell_exports());
+            }
+        }
+        Ok(())
+    }
+}
+
+fn no_profile_selected() -> ApiaryError {
+    ApiaryError::ConfigError(
+        "No profile selected. Use --profile, set APIARY_PROFILE, or run 'apiary config use <name>'"
+            .to_string(),
+    )
+}
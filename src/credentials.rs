@@ -0,0 +1,77 @@
+use crate::errors::ApiaryError;
+use std::collections::HashMap;
+use std::process::{Command, Stdio};
+use std::sync::{Mutex, OnceLock};
+
+/// Keys printed by helper commands, by command. Held in memory only, so each
+/// helper runs at most once per process and nothing outlives it.
+static RESOLVED: OnceLock<Mutex<HashMap<String, String>>> = OnceLock::new();
+
+/// Key printed by a credential helper such as `pass show honeycomb/config-key`
+/// or `op read op://vault/honeycomb/credential`.
+///
+/// The command runs with the shell; stdin and stderr stay attached to the
+/// terminal so helpers can prompt for unlocking. Surrounding whitespace in the
+/// output is ignored.
+pub fn key_from_command(command: &str) -> Result<String, ApiaryError> {
+    let resolved = RESOLVED.get_or_init(Default::default);
+    if let Some(key) = resolved
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(command)
+    {
+        return Ok(key.clone());
+    }
+
+    let key = run(command)?;
+    resolved
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(command.to_string(), key.clone());
+    Ok(key)
+}
+
+fn run(command: &str) -> Result<String, ApiaryError> {
+    let output = shell(command)
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| {
+            ApiaryError::ConfigError(format!("Failed to run key command '{}': {}", command, e))
+        })?;
+
+    // Never echo stdout in errors; a failing helper may still have printed a secret
+    if !output.status.success() {
+        return Err(ApiaryError::ConfigError(format!(
+            "Key command '{}' failed ({})",
+            command, output.status
+        )));
+    }
+    let key = String::from_utf8(output.stdout)
+        .map_err(|_| {
+            ApiaryError::ConfigError(format!("Key command '{}' printed invalid UTF-8", command))
+        })?
+        .trim()
+        .to_string();
+    if key.is_empty() {
+        return Err(ApiaryError::ConfigError(format!(
+            "Key command '{}' printed nothing",
+            command
+        )));
+    }
+    Ok(key)
+}
+
+#[cfg(unix)]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("sh");
+    shell.arg("-c").arg(command);
+    shell
+}
+
+#[cfg(windows)]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("cmd");
+    shell.arg("/C").arg(command);
+    shell
+}
@@ -1,6 +1,7 @@
 use crate::client::HoneycombClient;
 use crate::common::{
-    CommandContext, DEFAULT_PRETTY_FORMAT, OutputFormat, pretty_print_json, read_json_file,
+    CommandContext, DEFAULT_PRETTY_FORMAT, OutputFormat, environment_client, pretty_print_json,
+    read_json_file,
 };
 use anyhow::Result;
 use clap::Subcommand;
@@ -13,10 +14,10 @@ pub enum DatasetDefinitionCommands {
         #[arg(short, long)]
         dataset: String,
         /// Team slug (uses HONEYCOMB_TEAM env var if not specified)
-        #[arg(short, long, env = "HONEYCOMB_TEAM")]
+        #[arg(short, long)]
         team: Option<String>,
         /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
-        #[arg(short, long, env = "HONEYCOMB_ENVIRONMENT")]
+        #[arg(short, long)]
         environment: Option<String>,
         /// Output format
         #[arg(short, long, default_value = DEFAULT_PRETTY_FORMAT)]
@@ -28,10 +29,10 @@ pub enum DatasetDefinitionCommands {
         #[arg(short, long)]
         dataset: String,
         /// Team slug (uses HONEYCOMB_TEAM env var if not specified)
-        #[arg(short, long, env = "HONEYCOMB_TEAM")]
+        #[arg(short, long)]
         team: Option<String>,
         /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
-        #[arg(short, long, env = "HONEYCOMB_ENVIRONMENT")]
+        #[arg(short, long)]
         environment: Option<String>,
         /// Dataset definition data (JSON file path or inline JSON)
         #[arg(long)]
@@ -44,43 +45,31 @@ pub enum DatasetDefinitionCommands {
 
 impl DatasetDefinitionCommands {
     pub async fn execute(&self, client: &HoneycombClient, context: &CommandContext) -> Result<()> {
-        match self {
+        let (team, environment) = match self {
             DatasetDefinitionCommands::Get {
-                dataset,
-                team,
-                environment,
-                format,
-            } => {
-                let team_str = context
-                    .team
-                    .as_deref()
-                    .or(team.as_deref())
-                    .unwrap_or("default");
-                get_dataset_definitions(client, dataset, team_str, environment.as_deref(), format)
-                    .await
+                team, environment, ..
             }
+            | DatasetDefinitionCommands::Update {
+                team, environment, ..
+            } => (team, environment),
+        };
+        let context = CommandContext {
+            // The team only sharpens an unknown environment error, so it stays optional
+            team: team.clone().or_else(|| context.team.clone()),
+            ..context.clone()
+        };
+        let client = &environment_client(client, &context, environment.as_deref()).await?;
+
+        match self {
+            DatasetDefinitionCommands::Get {
+                dataset, format, ..
+            } => get_dataset_definitions(client, dataset, format).await,
             DatasetDefinitionCommands::Update {
                 dataset,
-                team,
-                environment,
                 data,
                 format,
-            } => {
-                let team_str = context
-                    .team
-                    .as_deref()
-                    .or(team.as_deref())
-                    .unwrap_or("default");
-                update_dataset_definitions(
-                    client,
-                    dataset,
-                    team_str,
-                    environment.as_deref(),
-                    data,
-                    format,
-                )
-                .await
-            }
+                ..
+            } => update_dataset_definitions(client, dataset, data, format).await,
         }
     }
 }
@@ -88,35 +77,10 @@ impl DatasetDefinitionCommands {
 async fn get_dataset_definitions(
     client: &HoneycombClient,
     dataset: &str,
-    team: &str,
-    environment: Option<&str>,
     format: &OutputFormat,
 ) -> Result<()> {
-    use crate::common::require_valid_environment;
-    use std::collections::HashMap;
-
-    // If environment is provided, validate it exists
-    if let Some(env) = environment {
-        require_valid_environment(client, team, env).await?;
-    }
-
-    // Build query parameters
-    let mut query_params = HashMap::new();
-    if let Some(env) = environment {
-        query_params.insert("environment".to_string(), env.to_string());
-    }
-
     let path = format!("/1/dataset_definitions/{}", dataset);
-    let response = client
-        .get(
-            &path,
-            if query_params.is_empty() {
-                None
-            } else {
-                Some(&query_params)
-            },
-        )
-        .await?;
+    let response = client.get(&path, None).await?;
 
     match format {
         OutputFormat::Json => {
@@ -133,33 +97,20 @@ async fn get_dataset_definitions(
 async fn update_dataset_definitions(
     client: &HoneycombClient,
     dataset: &str,
-    team: &str,
-    environment: Option<&str>,
     data: &str,
     format: &OutputFormat,
 ) -> Result<()> {
-    use crate::common::require_valid_environment;
-    use std::collections::HashMap;
-
-    // If environment is provided, validate it exists
-    if let Some(env) = environment {
-        require_valid_environment(client, team, env).await?;
-    }
-
     let json_data = if std::path::Path::new(data).exists() {
         read_json_file(data)?
     } else {
         serde_json::from_str(data)?
     };
 
-    // Build query parameters for the request if needed
-    let mut query_params = HashMap::new();
-    if let Some(env) = environment {
-        query_params.insert("environment".to_string(), env.to_string());
-    }
-
     let path = format!("/1/dataset_definitions/{}", dataset);
     let response = client.patch(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -1,9 +1,8 @@
 use crate::client::HoneycombClient;
 use crate::common::{
-    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, pretty_print_json,
-    read_json_file,
+    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, environment_client,
+    pretty_print_json, read_json_file,
 };
-use crate::errors;
 use anyhow::Result;
 use clap::Subcommand;
 use serde::{Deserialize, Serialize};
@@ -14,10 +13,10 @@ pub enum DatasetCommands {
     /// List all datasets
     List {
         /// Team slug (uses HONEYCOMB_TEAM env var if not specified)
-        #[arg(short, long, env = "HONEYCOMB_TEAM")]
+        #[arg(short, long)]
         team: Option<String>,
         /// Environment slug (uses HONEYCOMB_ENVIRONMENT env var if not specified)
-        #[arg(short, long, env = "HONEYCOMB_ENVIRONMENT")]
+        #[arg(short, long)]
         environment: Option<String>,
         /// Output format
         #[arg(short, long, default_value = DEFAULT_TABLE_FORMAT)]
@@ -28,12 +27,18 @@ pub enum DatasetCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Output format
         #[arg(short, long, default_value = DEFAULT_PRETTY_FORMAT)]
         format: OutputFormat,
     },
     /// Create a new dataset
     Create {
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Dataset data (JSON file path or inline JSON)
         #[arg(short, long)]
         data: String,
@@ -46,6 +51,9 @@ pub enum DatasetCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Dataset data (JSON file path or inline JSON)
         #[arg(short, long)]
         data: String,
@@ -58,6 +66,9 @@ pub enum DatasetCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
     },
 }
 
@@ -73,63 +84,47 @@ pub struct Dataset {
 }
 
 impl DatasetCommands {
+    fn environment(&self) -> Option<&str> {
+        match self {
+            DatasetCommands::List { environment, .. }
+            | DatasetCommands::Get { environment, .. }
+            | DatasetCommands::Create { environment, .. }
+            | DatasetCommands::Update { environment, .. }
+            | DatasetCommands::Delete { environment, .. } => environment.as_deref(),
+        }
+    }
+
     pub async fn execute(&self, client: &HoneycombClient, context: &CommandContext) -> Result<()> {
+        let context = match self {
+            DatasetCommands::List { team, .. } => CommandContext {
+                team: Some(context.team(team.as_deref())?.to_string()),
+                ..context.clone()
+            },
+            _ => context.clone(),
+        };
+        let client = &environment_client(client, &context, self.environment()).await?;
+
         match self {
-            DatasetCommands::List {
-                team,
-                environment,
-                format,
-            } => {
-                let effective_team = team
-                    .as_ref()
-                    .or(context.team.as_ref())
-                    .ok_or_else(|| anyhow::anyhow!(errors::messages::TEAM_REQUIRED))?;
-
-                // Environment is now optional - if not provided, list all datasets
-                list_datasets(client, effective_team, environment.as_deref(), format).await
+            DatasetCommands::List { format, .. } => list_datasets(client, format).await,
+            DatasetCommands::Get {
+                dataset, format, ..
+            } => get_dataset(client, dataset, format).await,
+            DatasetCommands::Create { data, format, .. } => {
+                create_dataset(client, data, format).await
             }
-            DatasetCommands::Get { dataset, format } => get_dataset(client, dataset, format).await,
-            DatasetCommands::Create { data, format } => create_dataset(client, data, format).await,
             DatasetCommands::Update {
                 dataset,
                 data,
                 format,
+                ..
             } => update_dataset(client, dataset, data, format).await,
-            DatasetCommands::Delete { dataset } => delete_dataset(client, dataset).await,
+            DatasetCommands::Delete { dataset, .. } => delete_dataset(client, dataset).await,
         }
     }
 }
 
-async fn list_datasets(
-    client: &HoneycombClient,
-    team: &str,
-    environment: Option<&str>,
-    format: &OutputFormat,
-) -> Result<()> {
-    use crate::common::require_valid_environment;
-    use std::collections::HashMap;
-
-    // If environment is provided, validate it exists
-    if let Some(env) = environment {
-        require_valid_environment(client, team, env).await?;
-    }
-
-    // Build query parameters
-    let mut query_params = HashMap::new();
-    if let Some(env) = environment {
-        query_params.insert("environment".to_string(), env.to_string());
-    }
-
-    let response = client
-        .get(
-            "/1/datasets",
-            if query_params.is_empty() {
-                None
-            } else {
-                Some(&query_params)
-            },
-        )
-        .await?;
+async fn list_datasets(client: &HoneycombClient, format: &OutputFormat) -> Result<()> {
+    let response = client.get("/1/datasets", None).await?;
 
     match format {
         OutputFormat::Json => {
@@ -193,6 +188,9 @@ async fn create_dataset(client: &HoneycombClient, data: &str, format: &OutputFor
     };
 
     let response = client.post("/1/datasets", &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -220,6 +218,9 @@ async fn update_dataset(
 
     let path = format!("/1/datasets/{}", dataset);
     let response = client.put(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -236,6 +237,9 @@ async fn update_dataset(
 async fn delete_dataset(client: &HoneycombClient, dataset: &str) -> Result<()> {
     let path = format!("/1/datasets/{}", dataset);
     client.delete(&path).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     println!("Dataset '{}' deleted successfully", dataset);
 
@@ -0,0 +1,174 @@
+use crate::logging::{REDACTED, is_sensitive_header};
+use crate::transport::{HttpRequest, HttpResponse};
+use flate2::read::GzDecoder;
+use reqwest::header::{CONTENT_ENCODING, HeaderMap};
+use reqwest::{Method, StatusCode};
+use std::io::Read;
+
+/// How `--dry-run` prints the request that would have been sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum DryRunFormat {
+    /// Raw HTTP request
+    Http,
+    /// Equivalent curl command
+    Curl,
+}
+
+/// POST endpoints that only read data: running a query and requesting SLO
+/// history or a service map. These are sent even in dry-run mode. Creating a
+/// query is held back, as it saves a query object on Honeycomb.
+const READ_ONLY_POSTS: [&str; 3] = [
+    "/1/query_results/",
+    "/1/reporting/slos/historical",
+    "/1/maps/dependencies/requests",
+];
+
+/// Whether a request changes state and should be held back in dry-run mode
+pub fn is_mutating(method: &Method, path: &str) -> bool {
+    match *method {
+        Method::GET | Method::HEAD | Method::OPTIONS => false,
+        Method::POST => !READ_ONLY_POSTS
+            .iter()
+            .any(|prefix| path.starts_with(prefix)),
+        _ => true,
+    }
+}
+
+/// Response handed back for a request held back by dry-run mode: a 200 echoing
+/// the JSON body (nothing for gzipped or empty bodies), so commands can carry on.
+/// Commands check [`HoneycombClient::is_dry_run`](crate::client::HoneycombClient::is_dry_run)
+/// rather than report it as a result.
+pub fn placeholder_response(request: &HttpRequest) -> HttpResponse {
+    let gzipped = request.headers.contains_key(CONTENT_ENCODING);
+    HttpResponse {
+        status: StatusCode::OK,
+        headers: HeaderMap::new(),
+        body: match &request.body {
+            Some(body) if !gzipped => body.clone(),
+            _ => Vec::new(),
+        },
+    }
+}
+
+/// Render a request as an HTTP snippet or curl command with API keys redacted
+pub fn render(format: DryRunFormat, request: &HttpRequest) -> String {
+    let headers: Vec<(String, String)> = request
+        .headers
+        .iter()
+        .map(|(name, value)| {
+            let name = name.as_str().to_string();
+            let value = redact_header(&name, value.to_str().unwrap_or_default(), format);
+            (name, value)
+        })
+        .collect();
+    let gzipped = request
+        .headers
+        .get("content-encoding")
+        .is_some_and(|v| v == "gzip");
+    let body = request
+        .body
+        .as_deref()
+        .map(|bytes| decode_body(bytes, gzipped, format == DryRunFormat::Http));
+
+    match format {
+        DryRunFormat::Http => render_http(request, &headers, body.as_deref()),
+        DryRunFormat::Curl => render_curl(request, &headers, body.as_deref(), gzipped),
+    }
+}
+
+/// Replace key values with a placeholder (or, for curl, the environment variable holding the key)
+fn redact_header(name: &str, value: &str, format: DryRunFormat) -> String {
+    if !is_sensitive_header(name) {
+        return value.to_string();
+    }
+    let secret = match (name.eq_ignore_ascii_case("authorization"), format) {
+        (true, DryRunFormat::Curl) => {
+            "$HONEYCOMB_MANAGEMENT_API_KEY_ID:$HONEYCOMB_MANAGEMENT_API_KEY"
+        }
+        (false, DryRunFormat::Curl) => "$HONEYCOMB_CONFIGURATION_API_KEY",
+        (_, DryRunFormat::Http) => REDACTED,
+    };
+    match value.split_once(' ') {
+        Some((scheme, _)) if scheme.eq_ignore_ascii_case("bearer") => {
+            format!("{} {}", scheme, secret)
+        }
+        _ => secret.to_string(),
+    }
+}
+
+/// Body as text, decompressing gzip and optionally pretty-printing JSON
+fn decode_body(bytes: &[u8], gzipped: bool, pretty: bool) -> String {
+    let mut raw = Vec::new();
+    let bytes = if gzipped && GzDecoder::new(bytes).read_to_end(&mut raw).is_ok() {
+        &raw[..]
+    } else {
+        bytes
+    };
+    let text = String::from_utf8_lossy(bytes).into_owned();
+    if !pretty {
+        return text;
+    }
+    serde_json::from_str::<serde_json::Value>(&text)
+        .and_then(|json| serde_json::to_string_pretty(&json))
+        .unwrap_or(text)
+}
+
+fn render_http(request: &HttpRequest, headers: &[(String, String)], body: Option<&str>) -> String {
+    let mut out = format!("{} {} HTTP/1.1\n", request.method, request.path_and_query());
+    out.push_str(&format!(
+        "Host: {}\n",
+        request.url.host_str().unwrap_or_default()
+    ));
+    for (name, value) in headers {
+        out.push_str(&format!("{}: {}\n", name, value));
+    }
+    if let Some(body) = body {
+        out.push('\n');
+        out.push_str(body);
+        out.push('\n');
+    }
+    out
+}
+
+fn render_curl(
+    request: &HttpRequest,
+    headers: &[(String, String)],
+    body: Option<&str>,
+    gzipped: bool,
+) -> String {
+    let mut parts = vec![format!(
+        "curl -X {} {}",
+        request.method,
+        shell_quote(request.url.as_str())
+    )];
+    for (name, value) in headers {
+        let header = format!("{}: {}", name, value);
+        // Double quotes so the key environment variables expand
+        if is_sensitive_header(name) {
+            parts.push(format!("-H \"{}\"", header));
+        } else {
+            parts.push(format!("-H {}", shell_quote(&header)));
+        }
+    }
+
+    match (body, gzipped) {
+        (Some(body), true) => {
+            parts.push("--data-binary @-".to_string());
+            format!(
+                "printf '%s' {} | gzip | {}\n",
+                shell_quote(body),
+                parts.join(" \\\n  ")
+            )
+        }
+        (Some(body), false) => {
+            parts.push(format!("--data-binary {}", shell_quote(body)));
+            format!("{}\n", parts.join(" \\\n  "))
+        }
+        (None, _) => format!("{}\n", parts.join(" \\\n  ")),
+    }
+}
+
+/// Quote a string for POSIX shells
+pub fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', r"'\''"))
+}
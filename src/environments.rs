@@ -3,18 +3,21 @@ use crate::common::{
     CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, pretty_print_json,
     read_json_file,
 };
-use crate::errors;
+use crate::pagination::{PageArgs, Pages, print_more_hint, write_json_list};
 use anyhow::Result;
 use clap::Subcommand;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Subcommand)]
 pub enum EnvironmentCommands {
     /// List all environments in a team
     List {
         /// Team slug (uses HONEYCOMB_TEAM env var if not specified)
-        #[arg(short, long, env = "HONEYCOMB_TEAM")]
+        #[arg(short, long)]
         team: Option<String>,
+        #[command(flatten)]
+        page: PageArgs,
         /// Output format
         #[arg(short, long, default_value = DEFAULT_TABLE_FORMAT)]
         format: OutputFormat,
@@ -22,7 +25,7 @@ pub enum EnvironmentCommands {
     /// Get a specific environment
     Get {
         /// Team slug (uses HONEYCOMB_TEAM env var if not specified)
-        #[arg(short, long, env = "HONEYCOMB_TEAM")]
+        #[arg(short, long)]
         team: Option<String>,
         /// Environment ID
         #[arg(short, long)]
@@ -34,7 +37,7 @@ pub enum EnvironmentCommands {
     /// Create a new environment
     Create {
         /// Team slug (uses HONEYCOMB_TEAM env var if not specified)
-        #[arg(short, long, env = "HONEYCOMB_TEAM")]
+        #[arg(short, long)]
         team: Option<String>,
         /// Environment data (JSON file path or inline JSON)
         #[arg(long)]
@@ -46,7 +49,7 @@ pub enum EnvironmentCommands {
     /// Update an environment
     Update {
         /// Team slug (uses HONEYCOMB_TEAM env var if not specified)
-        #[arg(short, long, env = "HONEYCOMB_TEAM")]
+        #[arg(short, long)]
         team: Option<String>,
         /// Environment ID
         #[arg(short, long)]
@@ -61,7 +64,7 @@ pub enum EnvironmentCommands {
     /// Delete an environment
     Delete {
         /// Team slug (uses HONEYCOMB_TEAM env var if not specified)
-        #[arg(short, long, env = "HONEYCOMB_TEAM")]
+        #[arg(short, long)]
         team: Option<String>,
         /// Environment ID
         #[arg(short, long)]
@@ -69,12 +72,6 @@ pub enum EnvironmentCommands {
     },
 }
 
-#[derive(Deserialize, Serialize, Debug)]
-pub struct EnvironmentsResponse {
-    pub data: Vec<EnvironmentData>,
-    pub links: Option<EnvironmentLinks>,
-}
-
 #[derive(Deserialize, Serialize, Debug)]
 pub struct EnvironmentData {
     pub id: String,
@@ -100,11 +97,6 @@ pub struct EnvironmentTimestamps {
     pub updated: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
-pub struct EnvironmentLinks {
-    pub next: Option<String>,
-}
-
 #[derive(Deserialize, Serialize, Debug)]
 pub struct EnvironmentSelfLink {
     #[serde(rename = "self")]
@@ -120,23 +112,16 @@ pub struct EnvironmentSettings {
 impl EnvironmentCommands {
     pub async fn execute(&self, client: &HoneycombClient, context: &CommandContext) -> Result<()> {
         match self {
-            EnvironmentCommands::List { team, format } => {
-                let effective_team = team
-                    .as_ref()
-                    .or(context.team.as_ref())
-                    .ok_or_else(|| anyhow::anyhow!(errors::messages::TEAM_REQUIRED))?;
-                list_environments(client, effective_team, format).await
+            EnvironmentCommands::List { team, page, format } => {
+                let effective_team = context.team(team.as_deref())?;
+                list_environments(client, effective_team, page, format).await
             }
             EnvironmentCommands::Get { team, id, format } => {
-                let effective_team = team
-                    .as_ref()
-                    .or(context.team.as_ref())
-                    .ok_or_else(|| anyhow::anyhow!(errors::messages::TEAM_REQUIRED))?;
+                let effective_team = context.team(team.as_deref())?;
                 get_environment(client, effective_team, id, format).await
             }
             EnvironmentCommands::Create { team, data, format } => {
-                let effective_team = team.as_ref().or(context.team.as_ref())
-                    .ok_or_else(|| anyhow::anyhow!("Team is required. Use --team flag or set HONEYCOMB_TEAM environment variable."))?;
+                let effective_team = context.team(team.as_deref())?;
                 create_environment(client, effective_team, data, format).await
             }
             EnvironmentCommands::Update {
@@ -145,13 +130,11 @@ impl EnvironmentCommands {
                 data,
                 format,
             } => {
-                let effective_team = team.as_ref().or(context.team.as_ref())
-                    .ok_or_else(|| anyhow::anyhow!("Team is required. Use --team flag or set HONEYCOMB_TEAM environment variable."))?;
+                let effective_team = context.team(team.as_deref())?;
                 update_environment(client, effective_team, id, data, format).await
             }
             EnvironmentCommands::Delete { team, id } => {
-                let effective_team = team.as_ref().or(context.team.as_ref())
-                    .ok_or_else(|| anyhow::anyhow!("Team is required. Use --team flag or set HONEYCOMB_TEAM environment variable."))?;
+                let effective_team = context.team(team.as_deref())?;
                 delete_environment(client, effective_team, id).await
             }
         }
@@ -161,48 +144,43 @@ impl EnvironmentCommands {
 async fn list_environments(
     client: &HoneycombClient,
     team: &str,
+    page: &PageArgs,
     format: &OutputFormat,
 ) -> Result<()> {
     let path = format!("/2/teams/{}/environments", team);
-    let response = client.get(&path, None).await?;
+    let mut pages = Pages::new(client, &path, HashMap::new(), page);
 
     match format {
-        OutputFormat::Json => {
-            println!("{}", serde_json::to_string(&response)?);
-        }
-        OutputFormat::Pretty => {
-            println!("{}", pretty_print_json(&response)?);
-        }
+        OutputFormat::Json | OutputFormat::Pretty => write_json_list(&mut pages, format).await?,
         OutputFormat::Table => {
-            if let Ok(env_response) =
-                serde_json::from_value::<EnvironmentsResponse>(response.clone())
-            {
-                println!(
-                    "{:<35} {:<15} {:<25} {:<12} Created",
-                    "ID", "Name", "Slug", "Color"
-                );
-                println!("{:-<95}", "");
+            println!(
+                "{:<35} {:<15} {:<25} {:<12} Created",
+                "ID", "Name", "Slug", "Color"
+            );
+            println!("{:-<95}", "");
 
-                for env_data in env_response.data {
-                    let color = env_data
-                        .attributes
-                        .color
-                        .unwrap_or_else(|| "N/A".to_string());
-                    println!(
-                        "{:<35} {:<15} {:<25} {:<12} {}",
-                        env_data.id,
-                        env_data.attributes.name,
-                        env_data.attributes.slug,
-                        color,
-                        env_data.attributes.timestamps.created
-                    );
+            while let Some(items) = pages.next_page().await? {
+                for item in items {
+                    if let Ok(env_data) = serde_json::from_value::<EnvironmentData>(item) {
+                        let color = env_data
+                            .attributes
+                            .color
+                            .unwrap_or_else(|| "N/A".to_string());
+                        println!(
+                            "{:<35} {:<15} {:<25} {:<12} {}",
+                            env_data.id,
+                            env_data.attributes.name,
+                            env_data.attributes.slug,
+                            color,
+                            env_data.attributes.timestamps.created
+                        );
+                    }
                 }
-            } else {
-                println!("{}", pretty_print_json(&response)?);
             }
         }
     }
 
+    print_more_hint(&pages);
     Ok(())
 }
 
@@ -241,6 +219,9 @@ async fn create_environment(
 
     let path = format!("/2/teams/{}/environments", team);
     let response = client.post(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -269,6 +250,9 @@ async fn update_environment(
 
     let path = format!("/2/teams/{}/environments/{}", team, id);
     let response = client.patch(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -285,6 +269,9 @@ async fn update_environment(
 async fn delete_environment(client: &HoneycombClient, team: &str, id: &str) -> Result<()> {
     let path = format!("/2/teams/{}/environments/{}", team, id);
     client.delete(&path).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     println!(
         "Environment '{}' in team '{}' deleted successfully",
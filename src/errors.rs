@@ -2,10 +2,9 @@ use serde_json::Value;
 use std::fmt;
 
 /// Standard error types for the CLI
-#[allow(dead_code)]
 #[derive(Debug)]
 pub enum ApiaryError {
-    /// Authentication required but not provided
+    /// Authentication required but not provided (message explains which key is missing)
     AuthenticationRequired(String),
     /// Resource not found
     NotFound {
@@ -14,8 +13,13 @@ pub enum ApiaryError {
     },
     /// Validation error
     ValidationError(String),
-    /// API communication error
-    ApiError { status: u16, message: String },
+    /// Error response from the API, with the parsed error body
+    ApiError {
+        status: u16,
+        message: String,
+        url: String,
+        body: Value,
+    },
     /// Configuration error
     ConfigError(String),
 }
@@ -23,12 +27,8 @@ pub enum ApiaryError {
 impl fmt::Display for ApiaryError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            ApiaryError::AuthenticationRequired(endpoint) => {
-                write!(
-                    f,
-                    "Authentication required for endpoint '{}'. Please set appropriate API keys.",
-                    endpoint
-                )
+            ApiaryError::AuthenticationRequired(msg) => {
+                write!(f, "{}", msg)
             }
             ApiaryError::NotFound {
                 resource,
@@ -39,8 +39,13 @@ impl fmt::Display for ApiaryError {
             ApiaryError::ValidationError(msg) => {
                 write!(f, "Validation error: {}", msg)
             }
-            ApiaryError::ApiError { status, message } => {
-                write!(f, "API error ({}): {}", status, message)
+            ApiaryError::ApiError {
+                status,
+                message,
+                url,
+                ..
+            } => {
+                write!(f, "API error ({}): {} (URL: {})", status, message, url)
             }
             ApiaryError::ConfigError(msg) => {
                 write!(f, "Configuration error: {}", msg)
@@ -51,17 +56,81 @@ impl fmt::Display for ApiaryError {
 
 impl std::error::Error for ApiaryError {}
 
-/// Helper function to parse API error responses
-#[allow(dead_code)]
-pub fn parse_api_error(status: u16, body: &Value) -> ApiaryError {
+impl ApiaryError {
+    /// Process exit code for this error (see [`exit_codes`])
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ApiaryError::AuthenticationRequired(_) => exit_codes::AUTH,
+            ApiaryError::NotFound { .. } => exit_codes::NOT_FOUND,
+            ApiaryError::ValidationError(_) => exit_codes::VALIDATION,
+            ApiaryError::ConfigError(_) => exit_codes::CONFIG,
+            ApiaryError::ApiError { status, .. } => match status {
+                401 | 403 => exit_codes::AUTH,
+                404 => exit_codes::NOT_FOUND,
+                400 | 409 | 422 => exit_codes::VALIDATION,
+                429 => exit_codes::RATE_LIMITED,
+                500..=599 => exit_codes::SERVER_ERROR,
+                _ => exit_codes::GENERAL,
+            },
+        }
+    }
+}
+
+/// Process exit codes, so scripts can tell failure classes apart.
+///
+/// Exit code 2 is reserved for invalid command line usage, reported by clap.
+pub mod exit_codes {
+    /// Any error not covered below
+    pub const GENERAL: i32 = 1;
+    /// Missing API key, or the API rejected the key (401/403)
+    pub const AUTH: i32 = 3;
+    /// Resource not found (404)
+    pub const NOT_FOUND: i32 = 4;
+    /// Invalid input, or the API rejected the request (400/409/422)
+    pub const VALIDATION: i32 = 5;
+    /// Rate limited by the API (429)
+    pub const RATE_LIMITED: i32 = 6;
+    /// Honeycomb server error (5xx)
+    pub const SERVER_ERROR: i32 = 7;
+    /// Invalid CLI configuration
+    pub const CONFIG: i32 = 8;
+}
+
+/// Exit code for an error chain, using the first [`ApiaryError`] found in it
+pub fn exit_code(err: &anyhow::Error) -> i32 {
+    err.chain()
+        .find_map(|e| e.downcast_ref::<ApiaryError>())
+        .map(ApiaryError::exit_code)
+        .unwrap_or(exit_codes::GENERAL)
+}
+
+/// Helper function to parse API error responses.
+///
+/// Understands v1 (`{"error": ...}`), RFC 7807 (`{"title", "detail"}`) and
+/// JSON:API (`{"errors": [...]}`) bodies; non-JSON bodies are passed as strings.
+pub fn parse_api_error(status: u16, body: &Value, url: &str) -> ApiaryError {
+    let first_error = body
+        .get("errors")
+        .and_then(|v| v.as_array())
+        .and_then(|errors| errors.first());
+
     let message = body
         .get("error")
         .or_else(|| body.get("message"))
+        .or_else(|| body.get("detail"))
+        .or_else(|| body.get("title"))
+        .or_else(|| first_error.and_then(|e| e.get("detail").or_else(|| e.get("title"))))
         .and_then(|v| v.as_str())
+        .or_else(|| body.as_str().filter(|s| !s.is_empty()))
         .unwrap_or("Unknown error")
         .to_string();
 
-    ApiaryError::ApiError { status, message }
+    ApiaryError::ApiError {
+        status,
+        message,
+        url: url.to_string(),
+        body: body.clone(),
+    }
 }
 
 /// Standard error messages
@@ -73,6 +142,14 @@ pub mod messages {
     pub const MANAGEMENT_KEY_REQUIRED: &str = "Management API key required for v2 endpoints. Set HONEYCOMB_MANAGEMENT_API_KEY_ID and HONEYCOMB_MANAGEMENT_API_KEY.";
     pub const CONFIG_KEY_REQUIRED: &str =
         "Configuration API key required for v1 endpoints. Set HONEYCOMB_CONFIGURATION_API_KEY.";
+    pub const NO_API_KEYS: &str = "No API keys configured. Set HONEYCOMB_MANAGEMENT_API_KEY_ID and HONEYCOMB_MANAGEMENT_API_KEY, or HONEYCOMB_CONFIGURATION_API_KEY.";
+
+    pub fn environment_key_mismatch(key_environment: &str, env: &str, key_var: &str) -> String {
+        format!(
+            "The configuration key belongs to environment '{}', not '{}'. Set {} or map a key for '{}' in your profile's environment_keys.",
+            key_environment, env, key_var, env
+        )
+    }
 
     pub fn environment_not_found(env: &str, team: &str) -> String {
         format!(
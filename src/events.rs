@@ -0,0 +1,351 @@
+use crate::client::HoneycombClient;
+use crate::common::{
+    CommandContext, DEFAULT_PRETTY_FORMAT, OutputFormat, environment_client, read_json_file,
+};
+use crate::errors::ApiaryError;
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::io::BufRead;
+
+/// Default number of events sent per batch request
+pub const DEFAULT_BATCH_SIZE: usize = 500;
+
+#[derive(Subcommand)]
+pub enum EventCommands {
+    /// Send a single event to a dataset
+    Send {
+        /// Dataset slug
+        #[arg(short, long)]
+        dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
+        /// Event data (JSON file path or inline JSON object)
+        #[arg(long)]
+        data: String,
+        /// Event time (RFC3339 or Unix timestamp), defaults to the time of receipt
+        #[arg(long)]
+        timestamp: Option<String>,
+        /// Sample rate the event represents (e.g. 10 for 1-in-10 sampling)
+        #[arg(long)]
+        sample_rate: Option<u32>,
+        /// Output format
+        #[arg(short, long, default_value = DEFAULT_PRETTY_FORMAT)]
+        format: OutputFormat,
+    },
+    /// Send events from a newline-delimited JSON file in batches
+    Batch {
+        /// Dataset slug
+        #[arg(short, long)]
+        dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
+        /// NDJSON file path, or - to read from stdin
+        #[arg(long)]
+        file: String,
+        /// Number of events per batch request
+        #[arg(long, default_value_t = DEFAULT_BATCH_SIZE)]
+        batch_size: usize,
+        /// Output format
+        #[arg(short, long, default_value = DEFAULT_PRETTY_FORMAT)]
+        format: OutputFormat,
+    },
+}
+
+/// A single event in a batch request
+#[derive(Deserialize, Serialize, Debug)]
+pub struct BatchEvent {
+    pub data: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub samplerate: Option<u32>,
+}
+
+/// Per-event status returned by the batch endpoint
+#[derive(Deserialize, Serialize, Debug)]
+pub struct BatchEventStatus {
+    pub status: u16,
+    pub error: Option<String>,
+}
+
+/// An event that was not accepted, identified by its input line number
+#[derive(Serialize, Debug)]
+pub struct BatchFailure {
+    pub line: usize,
+    pub status: Option<u16>,
+    pub error: String,
+}
+
+#[derive(Serialize, Debug, Default)]
+pub struct BatchSummary {
+    pub sent: usize,
+    pub accepted: usize,
+    pub failed: Vec<BatchFailure>,
+}
+
+impl EventCommands {
+    fn environment(&self) -> Option<&str> {
+        match self {
+            EventCommands::Send { environment, .. } | EventCommands::Batch { environment, .. } => {
+                environment.as_deref()
+            }
+        }
+    }
+
+    pub async fn execute(&self, client: &HoneycombClient, context: &CommandContext) -> Result<()> {
+        let client = &environment_client(client, context, self.environment()).await?;
+
+        match self {
+            EventCommands::Send {
+                dataset,
+                data,
+                timestamp,
+                sample_rate,
+                format,
+                ..
+            } => {
+                send_event(
+                    client,
+                    dataset,
+                    data,
+                    timestamp.as_deref(),
+                    *sample_rate,
+                    format,
+                )
+                .await
+            }
+            EventCommands::Batch {
+                dataset,
+                file,
+                batch_size,
+                format,
+                ..
+            } => send_batch(client, dataset, file, *batch_size, format).await,
+        }
+    }
+}
+
+async fn send_event(
+    client: &HoneycombClient,
+    dataset: &str,
+    data: &str,
+    timestamp: Option<&str>,
+    sample_rate: Option<u32>,
+    format: &OutputFormat,
+) -> Result<()> {
+    let json_data = if std::path::Path::new(data).exists() {
+        read_json_file(data)?
+    } else {
+        serde_json::from_str(data)?
+    };
+
+    if !json_data.is_object() {
+        return Err(
+            ApiaryError::ValidationError("Event data must be a JSON object".to_string()).into(),
+        );
+    }
+
+    let mut headers = HashMap::new();
+    if let Some(ts) = timestamp {
+        headers.insert("X-Honeycomb-Event-Time".to_string(), ts.to_string());
+    }
+    if let Some(rate) = sample_rate {
+        headers.insert("X-Honeycomb-Samplerate".to_string(), rate.to_string());
+    }
+
+    let path = format!("/1/events/{}", dataset);
+    client
+        .post_with_headers(&path, &json_data, &headers)
+        .await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
+
+    match format {
+        OutputFormat::Json => {
+            println!(
+                "{}",
+                serde_json::to_string(&json!({ "dataset": dataset, "sent": 1 }))?
+            );
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!("Event sent to dataset '{}'", dataset);
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse an NDJSON line into a batch event.
+///
+/// Lines may either be a bare event object or a batch envelope with `data`
+/// and optional `time` and `samplerate` keys.
+pub fn parse_batch_line(line: &str) -> Result<BatchEvent> {
+    let value: Value = serde_json::from_str(line)?;
+    let obj = value
+        .as_object()
+        .ok_or_else(|| anyhow::anyhow!("Event must be a JSON object"))?;
+
+    let is_envelope = obj.get("data").is_some_and(Value::is_object)
+        && obj
+            .keys()
+            .all(|k| matches!(k.as_str(), "data" | "time" | "samplerate"));
+
+    if is_envelope {
+        Ok(serde_json::from_value(value)?)
+    } else {
+        Ok(BatchEvent {
+            data: value,
+            time: None,
+            samplerate: None,
+        })
+    }
+}
+
+/// Send NDJSON events from a reader in gzipped batches and collect per-event results
+pub async fn send_batch_from_reader<R: BufRead>(
+    client: &HoneycombClient,
+    dataset: &str,
+    reader: R,
+    batch_size: usize,
+) -> Result<BatchSummary> {
+    if batch_size == 0 {
+        return Err(ApiaryError::ValidationError(
+            "Batch size must be greater than zero".to_string(),
+        )
+        .into());
+    }
+
+    let path = format!("/1/batch/{}", dataset);
+    let mut summary = BatchSummary::default();
+    let mut events = Vec::with_capacity(batch_size);
+    let mut lines = Vec::with_capacity(batch_size);
+
+    for (index, line) in reader.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.with_context(|| format!("Failed to read line {}", line_number))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match parse_batch_line(&line) {
+            Ok(event) => {
+                events.push(event);
+                lines.push(line_number);
+            }
+            Err(e) => summary.failed.push(BatchFailure {
+                line: line_number,
+                status: None,
+                error: e.to_string(),
+            }),
+        }
+
+        if events.len() == batch_size {
+            send_chunk(client, &path, &mut events, &mut lines, &mut summary).await?;
+        }
+    }
+
+    if !events.is_empty() {
+        send_chunk(client, &path, &mut events, &mut lines, &mut summary).await?;
+    }
+
+    summary.failed.sort_by_key(|f| f.line);
+    Ok(summary)
+}
+
+async fn send_chunk(
+    client: &HoneycombClient,
+    path: &str,
+    events: &mut Vec<BatchEvent>,
+    lines: &mut Vec<usize>,
+    summary: &mut BatchSummary,
+) -> Result<()> {
+    let body = serde_json::to_value(&*events)?;
+    let response = client.post_gzip(path, &body).await?;
+    // A dry run sends nothing, so there are no per-event statuses to record
+    if client.is_dry_run() {
+        events.clear();
+        lines.clear();
+        return Ok(());
+    }
+    let statuses: Vec<BatchEventStatus> =
+        serde_json::from_value(response).context("Unexpected batch response")?;
+
+    summary.sent += events.len();
+    for (i, line) in lines.iter().enumerate() {
+        match statuses.get(i) {
+            Some(s) if (200..300).contains(&s.status) => summary.accepted += 1,
+            Some(s) => summary.failed.push(BatchFailure {
+                line: *line,
+                status: Some(s.status),
+                error: s
+                    .error
+                    .clone()
+                    .unwrap_or_else(|| "Unknown error".to_string()),
+            }),
+            None => summary.failed.push(BatchFailure {
+                line: *line,
+                status: None,
+                error: "No status returned for event".to_string(),
+            }),
+        }
+    }
+
+    events.clear();
+    lines.clear();
+    Ok(())
+}
+
+async fn send_batch(
+    client: &HoneycombClient,
+    dataset: &str,
+    file: &str,
+    batch_size: usize,
+    format: &OutputFormat,
+) -> Result<()> {
+    let summary = if file == "-" {
+        let stdin = std::io::stdin();
+        send_batch_from_reader(client, dataset, stdin.lock(), batch_size).await?
+    } else {
+        let handle = std::fs::File::open(file)
+            .with_context(|| format!("Failed to open event file '{}'", file))?;
+        send_batch_from_reader(client, dataset, std::io::BufReader::new(handle), batch_size).await?
+    };
+    if client.is_dry_run() {
+        return Ok(());
+    }
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(&summary)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!(
+                "Sent {} events to dataset '{}': {} accepted, {} failed",
+                summary.sent,
+                dataset,
+                summary.accepted,
+                summary.failed.len()
+            );
+            for failure in &summary.failed {
+                match failure.status {
+                    Some(status) => {
+                        println!("  line {}: {} ({})", failure.line, failure.error, status)
+                    }
+                    None => println!("  line {}: {}", failure.line, failure.error),
+                }
+            }
+        }
+    }
+
+    if !summary.failed.is_empty() {
+        anyhow::bail!("{} events failed to send", summary.failed.len());
+    }
+
+    Ok(())
+}
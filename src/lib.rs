@@ -1,16 +1,33 @@
+pub mod api;
+pub mod api_keys;
 pub mod auth;
 pub mod boards;
 pub mod burn_alerts;
+pub mod cache;
 pub mod calculated_fields;
+pub mod cassette;
 pub mod client;
 pub mod columns;
 pub mod common;
+pub mod config;
+pub mod credentials;
 pub mod dataset_definitions;
 pub mod datasets;
+pub mod dry_run;
 pub mod environments;
 pub mod errors;
+pub mod events;
+pub mod logging;
 pub mod marker_settings;
 pub mod markers;
+pub mod pagination;
+pub mod queries;
+pub mod query_annotations;
+pub mod rate_limit;
 pub mod recipients;
+pub mod region;
+pub mod retry;
+pub mod service_map;
 pub mod slos;
+pub mod transport;
 pub mod triggers;
@@ -0,0 +1,144 @@
+use anyhow::{Context, Result};
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+use std::fs::OpenOptions;
+use std::io::IsTerminal;
+use std::path::Path;
+use std::sync::Mutex;
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::prelude::*;
+
+/// Placeholder written in place of secret values
+pub const REDACTED: &str = "<redacted>";
+
+/// Headers that carry API keys
+const SENSITIVE_HEADERS: &[&str] = &["authorization", "x-honeycomb-team"];
+
+/// Body fields holding credentials, e.g. recipient `details` and API key
+/// secrets: fields with one of these names or ending in `_<name>`
+const SENSITIVE_FIELDS: &[&str] = &[
+    "secret",
+    "token",
+    "password",
+    "integration_key",
+    "api_key",
+    "authorization",
+];
+
+/// Recipient fields that carry credentials without a telling name
+const SENSITIVE_RECIPIENT_FIELDS: &[&str] = &["webhook_url", "webhook_headers"];
+
+/// Set up logging for the given number of `-v` flags.
+///
+/// `-v` logs one line per request and response (timing, status, body size);
+/// `-vv` adds redacted headers and full bodies. Logs go to stderr unless a
+/// log file is given, in which case they are appended to it.
+pub fn init(verbosity: u8, log_file: Option<&Path>) -> Result<()> {
+    let level = match verbosity {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    };
+    // Only our own events; dependencies are far too chatty at debug level
+    let filter = Targets::new().with_target(env!("CARGO_CRATE_NAME"), level);
+    let layer = tracing_subscriber::fmt::layer()
+        .with_target(false)
+        .with_writer(std::io::stderr);
+
+    match log_file {
+        Some(path) => {
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .with_context(|| format!("Failed to open log file '{}'", path.display()))?;
+            tracing_subscriber::registry()
+                .with(layer.with_ansi(false).with_writer(Mutex::new(file)))
+                .with(filter)
+                .init();
+        }
+        None => {
+            // Colors only for a terminal; piped or CI logs get plain text
+            tracing_subscriber::registry()
+                .with(layer.with_ansi(std::io::stderr().is_terminal()))
+                .with(filter)
+                .init();
+        }
+    }
+
+    Ok(())
+}
+
+pub fn is_sensitive_header(name: &str) -> bool {
+    SENSITIVE_HEADERS.contains(&name.to_ascii_lowercase().as_str())
+}
+
+/// Render headers as `name: value` lines with API keys replaced
+pub fn redact_headers(headers: &HeaderMap) -> String {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if is_sensitive_header(name.as_str()) {
+                REDACTED
+            } else {
+                value.to_str().unwrap_or("<binary>")
+            };
+            format!("{}: {}", name, value)
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Whether a body field holds a credential. Names are matched whole or by
+/// `_`-separated suffix, so `webhook_secret` is hidden but `token_count` and
+/// `api_key_access` are not.
+fn is_sensitive_field(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    SENSITIVE_RECIPIENT_FIELDS.contains(&key.as_str())
+        || SENSITIVE_FIELDS.iter().any(|field| {
+            key.strip_suffix(field)
+                .is_some_and(|prefix| prefix.is_empty() || prefix.ends_with('_'))
+        })
+}
+
+/// Copy of a JSON body with credential fields replaced
+pub fn redact_json(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| {
+                    let value = if is_sensitive_field(key) {
+                        Value::String(REDACTED.to_string())
+                    } else {
+                        redact_json(value)
+                    };
+                    (key.clone(), value)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(redact_json).collect()),
+        other => other.clone(),
+    }
+}
+
+/// Redact a response or request body given as text (non-JSON text is passed through)
+pub fn redact_body(text: &str) -> String {
+    match serde_json::from_str::<Value>(text) {
+        Ok(json) => redact_json(&json).to_string(),
+        Err(_) => text.to_string(),
+    }
+}
+
+/// Short, panic-free hint of a key for diagnostics: the key ID for
+/// management keys (`id:secret`), otherwise a short prefix of long keys.
+pub fn mask_key(key: &str) -> String {
+    if let Some((id, _)) = key.split_once(':') {
+        return format!("{}:{}", id, REDACTED);
+    }
+    match key.get(..8) {
+        Some(prefix) if key.len() >= 16 => format!("{}...", prefix),
+        _ => REDACTED.to_string(),
+    }
+}
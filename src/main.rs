@@ -1,26 +1,46 @@
+mod api;
+mod api_keys;
 mod auth;
 mod boards;
 mod burn_alerts;
+mod cache;
 mod calculated_fields;
+mod cassette;
 mod client;
 mod columns;
 mod common;
+mod config;
+mod credentials;
 mod dataset_definitions;
 mod datasets;
+mod dry_run;
 mod environments;
 mod errors;
+mod events;
+mod logging;
 mod marker_settings;
 mod markers;
-
+mod pagination;
+mod queries;
+mod query_annotations;
+mod rate_limit;
 mod recipients;
+mod region;
+mod retry;
+mod service_map;
 mod slos;
+mod transport;
 mod triggers;
 
 use anyhow::Result;
-use clap::{Parser, Subcommand};
+use clap::parser::ValueSource;
+use clap::{ArgAction, ArgMatches, CommandFactory, FromArgMatches, Parser, Subcommand};
 use client::HoneycombClient;
 use common::OutputFormat;
 use std::env;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
 
 #[derive(Parser)]
 #[command(name = "apiary")]
@@ -52,6 +72,15 @@ struct Cli {
     )]
     config_key: Option<String>,
 
+    /// Command that prints the Configuration API key (takes precedence over HONEYCOMB_CONFIGURATION_API_KEY)
+    #[arg(long, env = "HONEYCOMB_CONFIGURATION_API_KEY_COMMAND")]
+    config_key_command: Option<String>,
+
+    /// Command that prints the Management API key secret, or `id:secret` when no
+    /// key ID is set (takes precedence over HONEYCOMB_MANAGEMENT_API_KEY)
+    #[arg(long, env = "HONEYCOMB_MANAGEMENT_API_KEY_COMMAND")]
+    management_key_command: Option<String>,
+
     /// Legacy: Honeycomb API key (will use as Management key if others not provided)
     #[arg(
         short,
@@ -65,7 +94,11 @@ struct Cli {
     #[arg(long, env = "HONEYCOMB_API_URL")]
     api_url: Option<String>,
 
-    /// Honeycomb API endpoint (e.g., eu1.api.honeycomb.io)
+    /// Honeycomb region, selecting its API host (ignored when --api-url is set)
+    #[arg(long, value_enum, env = "HONEYCOMB_REGION")]
+    region: Option<region::Region>,
+
+    /// Honeycomb API endpoint (e.g., api.eu1.honeycomb.io); prefer --region
     #[arg(long, env = "HONEYCOMB_API_ENDPOINT")]
     api_endpoint: Option<String>,
 
@@ -73,13 +106,123 @@ struct Cli {
     #[arg(long, env = "HONEYCOMB_TEAM")]
     team: Option<String>,
 
+    /// Environment slug for commands that don't set their own --environment
+    #[arg(long, global = true, env = "HONEYCOMB_ENVIRONMENT")]
+    environment: Option<String>,
+
+    /// Named profile from the config file to use for defaults
+    #[arg(long, global = true, env = "APIARY_PROFILE")]
+    profile: Option<String>,
+
+    /// Config file with named profiles (default: ~/.config/apiary/config.toml)
+    #[arg(long, global = true, env = "APIARY_CONFIG_FILE")]
+    config_file: Option<PathBuf>,
+
     /// Global output format override
     #[arg(long, global = true)]
     format: Option<OutputFormat>,
 
-    /// Verbose output
-    #[arg(short, long, global = true)]
-    verbose: bool,
+    /// Log requests and responses to stderr (-v: timing and status, -vv: headers and bodies)
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+
+    /// Append logs to this file instead of stderr
+    #[arg(long, global = true, env = "APIARY_LOG_FILE")]
+    log_file: Option<PathBuf>,
+
+    /// Maximum number of retries for rate-limited (429) and server error (5xx) responses
+    #[arg(long, global = true, env = "APIARY_MAX_RETRIES", default_value_t = retry::DEFAULT_MAX_RETRIES)]
+    max_retries: u32,
+
+    /// Maximum total seconds to spend retrying a single request
+    #[arg(long, global = true, env = "APIARY_RETRY_TIMEOUT", default_value_t = retry::DEFAULT_RETRY_TIMEOUT_SECS)]
+    retry_timeout: u64,
+
+    /// Requests per second sent to the v1 configuration API (0 for no limit)
+    #[arg(long, global = true, env = "APIARY_CONFIG_RATE_LIMIT", default_value_t = rate_limit::DEFAULT_CONFIG_RATE_LIMIT)]
+    config_rate_limit: f64,
+
+    /// Requests per second sent to the v2 management API (0 for no limit)
+    #[arg(long, global = true, env = "APIARY_MANAGEMENT_RATE_LIMIT", default_value_t = rate_limit::DEFAULT_MANAGEMENT_RATE_LIMIT)]
+    management_rate_limit: f64,
+
+    /// Maximum v1 configuration API requests in flight at once
+    #[arg(long, global = true, env = "APIARY_CONFIG_MAX_CONCURRENCY", default_value_t = rate_limit::DEFAULT_MAX_CONCURRENCY)]
+    config_max_concurrency: usize,
+
+    /// Maximum v2 management API requests in flight at once
+    #[arg(long, global = true, env = "APIARY_MANAGEMENT_MAX_CONCURRENCY", default_value_t = rate_limit::DEFAULT_MAX_CONCURRENCY)]
+    management_max_concurrency: usize,
+
+    /// Total seconds allowed for each HTTP request
+    #[arg(long, global = true, env = "APIARY_TIMEOUT")]
+    timeout: Option<u64>,
+
+    /// Seconds allowed for establishing a connection
+    #[arg(long, global = true, env = "APIARY_CONNECT_TIMEOUT")]
+    connect_timeout: Option<u64>,
+
+    /// HTTP(S) proxy URL for all requests (HTTPS_PROXY/HTTP_PROXY are also honored)
+    #[arg(long, global = true, env = "APIARY_PROXY")]
+    proxy: Option<String>,
+
+    /// PEM file with additional CA certificates to trust
+    #[arg(long, global = true, env = "APIARY_CA_BUNDLE")]
+    ca_bundle: Option<PathBuf>,
+
+    /// PEM client certificate for mutual TLS (requires --client-key)
+    #[arg(
+        long,
+        global = true,
+        env = "APIARY_CLIENT_CERT",
+        requires = "client_key"
+    )]
+    client_cert: Option<PathBuf>,
+
+    /// PEM (PKCS#8) private key for the mutual TLS client certificate
+    #[arg(
+        long,
+        global = true,
+        env = "APIARY_CLIENT_KEY",
+        requires = "client_cert"
+    )]
+    client_key: Option<PathBuf>,
+
+    /// Custom User-Agent header
+    #[arg(long, global = true, env = "APIARY_USER_AGENT")]
+    user_agent: Option<String>,
+
+    /// Print mutating requests (as an HTTP snippet or curl command) instead of sending them
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "http",
+        value_name = "FORMAT"
+    )]
+    dry_run: Option<dry_run::DryRunFormat>,
+
+    /// Cache GET responses on disk for this many seconds (caching is off unless set)
+    #[arg(long, global = true, env = "APIARY_CACHE_TTL", value_name = "SECONDS")]
+    cache_ttl: Option<u64>,
+
+    /// Bypass the response cache for this invocation
+    #[arg(long, global = true)]
+    no_cache: bool,
+
+    /// Directory for cached responses (default: $XDG_CACHE_HOME/apiary or ~/.cache/apiary)
+    #[arg(long, global = true, env = "APIARY_CACHE_DIR")]
+    cache_dir: Option<PathBuf>,
+
+    /// Save every request and response to this cassette file (API keys are scrubbed)
+    #[arg(long, global = true, value_name = "PATH", conflicts_with = "replay")]
+    record: Option<PathBuf>,
+
+    /// Serve responses from this cassette file instead of the network
+    #[arg(long, global = true, value_name = "PATH")]
+    replay: Option<PathBuf>,
 
     #[command(subcommand)]
     command: Option<Commands>,
@@ -87,6 +230,23 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Send a raw request to any API endpoint
+    Api(api::ApiArgs),
+    /// Manage the local response cache
+    Cache {
+        #[command(subcommand)]
+        command: cache::CacheCommands,
+    },
+    /// Manage named profiles in the config file
+    Config {
+        #[command(subcommand)]
+        command: config::ConfigCommands,
+    },
+    /// API key management (v2 API)
+    ApiKeys {
+        #[command(subcommand)]
+        command: api_keys::ApiKeyCommands,
+    },
     /// Authentication operations
     Auth {
         #[command(subcommand)]
@@ -118,11 +278,23 @@ enum Commands {
         #[command(subcommand)]
         command: markers::MarkerCommands,
     },
+    /// Query management
+    Queries {
+        #[command(subcommand)]
+        command: queries::QueryCommands,
+    },
+    /// Query Annotation management
+    QueryAnnotations {
+        #[command(subcommand)]
+        command: query_annotations::QueryAnnotationCommands,
+    },
     /// Recipient management
     Recipients {
         #[command(subcommand)]
         command: recipients::RecipientCommands,
     },
+    /// Service map dependencies
+    ServiceMap(service_map::ServiceMapArgs),
     /// SLO management
     Slos {
         #[command(subcommand)]
@@ -138,6 +310,11 @@ enum Commands {
         #[command(subcommand)]
         command: environments::EnvironmentCommands,
     },
+    /// Event ingestion
+    Events {
+        #[command(subcommand)]
+        command: events::EventCommands,
+    },
     /// Calculated Fields (Derived Columns) management
     CalculatedFields {
         #[command(subcommand)]
@@ -156,12 +333,142 @@ enum Commands {
 }
 
 #[tokio::main]
-async fn main() -> Result<()> {
-    let cli = Cli::parse();
+async fn main() {
+    let (cli, profile) = match parse_cli() {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("Error: {:?}", err);
+            std::process::exit(errors::exit_code(&err));
+        }
+    };
+    let verbose = cli.verbose > 0;
+
+    if let Err(err) = run(cli, profile).await {
+        eprintln!("Error: {:?}", err);
+        if verbose
+            && let Some(errors::ApiaryError::ApiError { body, .. }) = err
+                .chain()
+                .find_map(|e| e.downcast_ref::<errors::ApiaryError>())
+        {
+            eprintln!(
+                "Response body: {}",
+                common::pretty_print_json(&logging::redact_json(body)).unwrap_or_default()
+            );
+        }
+        // Keys only work in the region their team lives in
+        if let Some(errors::ApiaryError::ApiError { status: 401, .. }) = err
+            .chain()
+            .find_map(|e| e.downcast_ref::<errors::ApiaryError>())
+        {
+            eprintln!(
+                "Hint: if your team is in another region, try --region (see 'apiary auth region')"
+            );
+        }
+        std::process::exit(errors::exit_code(&err));
+    }
+}
+
+/// Parse the command line, taking defaults from the active profile if there is one
+fn parse_cli() -> Result<(Cli, Option<config::Profile>)> {
+    let cli = cli_from_matches(&Cli::command().get_matches());
+
+    // `config` commands manage profiles themselves, including ones not created yet
+    if matches!(cli.command, Some(Commands::Config { .. })) {
+        return Ok((cli, None));
+    }
+    let Some(path) = cli.config_file.clone().or_else(config::default_config_path) else {
+        return Ok((cli, None));
+    };
+    let file = config::ConfigFile::load(&path)?;
+
+    match file.active_profile(cli.profile.as_deref(), &path)? {
+        Some(profile) => {
+            let cli = cli_from_matches(&profile.apply(Cli::command()).get_matches());
+            Ok((cli, Some(profile.clone())))
+        }
+        None => Ok((cli, None)),
+    }
+}
+
+fn cli_from_matches(matches: &ArgMatches) -> Cli {
+    let mut cli = Cli::from_arg_matches(matches).unwrap_or_else(|e| e.exit());
+
+    // --api-url, --region and --api-endpoint all pick the API host
+    let api_host = ["api_url", "region", "api_endpoint"];
+    if shadowed_by_flag(matches, &api_host, "api_url") {
+        cli.api_url = None;
+    }
+    if shadowed_by_flag(matches, &api_host, "region") {
+        cli.region = None;
+    }
+    if shadowed_by_flag(matches, &api_host, "api_endpoint") {
+        cli.api_endpoint = None;
+    }
+
+    // A key given as a flag beats a key command from the profile or environment
+    let config_key = ["config_key", "config_key_command"];
+    if shadowed_by_flag(matches, &config_key, "config_key_command") {
+        cli.config_key_command = None;
+    }
+    let management_key = ["management_key_secret", "management_key_command"];
+    if shadowed_by_flag(matches, &management_key, "management_key_command") {
+        cli.management_key_command = None;
+    }
+    cli
+}
+
+/// Whether option `id` was set by the profile or the environment while
+/// another option in `group`, which picks the same setting, was given as a
+/// flag. The flag wins whatever the options' usual precedence.
+fn shadowed_by_flag(matches: &ArgMatches, group: &[&str], id: &str) -> bool {
+    let from_flag = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+    !from_flag(id) && group.iter().any(|other| from_flag(other))
+}
+
+async fn run(cli: Cli, profile: Option<config::Profile>) -> Result<()> {
+    logging::init(cli.verbose, cli.log_file.as_deref())
+        .map_err(|e| errors::ApiaryError::ConfigError(format!("{:#}", e)))?;
+
+    let cache_dir = cli.cache_dir.clone().or_else(cache::default_cache_dir);
+    // Commands that only touch local state don't need API keys or a client
+    match &cli.command {
+        Some(Commands::Cache { command }) => return command.execute(cache_dir.as_deref()),
+        Some(Commands::Config { command }) => {
+            let path = cli
+                .config_file
+                .clone()
+                .or_else(config::default_config_path)
+                .ok_or_else(|| {
+                    errors::ApiaryError::ConfigError(
+                        "Cannot locate the config file; set --config-file or APIARY_CONFIG_FILE"
+                            .to_string(),
+                    )
+                })?;
+            return command.execute(&path, cli.profile.as_deref());
+        }
+        _ => {}
+    }
+
+    // Keys from helper commands are resolved once, here, and never exported
+    let (management_key_id, management_key_secret) = match &cli.management_key_command {
+        Some(command) => {
+            let output = credentials::key_from_command(command)?;
+            match output.split_once(':') {
+                Some((id, secret)) if cli.management_key_id.is_none() => {
+                    (Some(id.to_string()), Some(secret.to_string()))
+                }
+                _ => (cli.management_key_id.clone(), Some(output)),
+            }
+        }
+        None => (
+            cli.management_key_id.clone(),
+            cli.management_key_secret.clone(),
+        ),
+    };
 
     // Determine which keys to use
     let management_key =
-        if let (Some(id), Some(secret)) = (&cli.management_key_id, &cli.management_key_secret) {
+        if let (Some(id), Some(secret)) = (&management_key_id, &management_key_secret) {
             Some(format!("{}:{}", id, secret))
         } else {
             // Fall back to api_key if it looks like a management key
@@ -174,7 +481,11 @@ async fn main() -> Result<()> {
             })
         };
 
-    let config_key = cli.config_key.or_else(|| {
+    let config_key = match &cli.config_key_command {
+        Some(command) => Some(credentials::key_from_command(command)?),
+        None => cli.config_key,
+    }
+    .or_else(|| {
         // Fall back to api_key if it looks like a config key
         cli.api_key.as_ref().and_then(|key| {
             if key.starts_with("hcaik_") || (key.len() == 64 && !key.contains(":")) {
@@ -185,43 +496,88 @@ async fn main() -> Result<()> {
         })
     });
 
-    // Construct the API URL - prioritize api_url, then construct from api_endpoint
-    let api_url = cli.api_url.or_else(|| {
-        cli.api_endpoint.map(|endpoint| {
-            if endpoint.starts_with("http://") || endpoint.starts_with("https://") {
-                endpoint
-            } else {
-                format!("https://{}", endpoint)
-            }
-        })
-    });
+    let api_url = region::resolve_api_url(cli.api_url, cli.region, cli.api_endpoint);
 
-    if cli.verbose {
-        if let Some(ref mgmt_key) = management_key {
-            eprintln!("Management Key: {}...", &mgmt_key[..8]);
-        }
-        if let Some(ref conf_key) = config_key {
-            eprintln!("Configuration Key: {}...", &conf_key[..8]);
-        }
-        if let Some(ref url) = api_url {
-            eprintln!("API URL: {}", url);
-        }
-        if let Some(ref team) = cli.team {
-            eprintln!("Team: {}", team);
-        }
+    if let Some(ref mgmt_key) = management_key {
+        tracing::info!("Management Key: {}", logging::mask_key(mgmt_key));
+    }
+    if let Some(ref conf_key) = config_key {
+        tracing::info!("Configuration Key: {}", logging::mask_key(conf_key));
     }
+    if let Some(ref url) = api_url {
+        tracing::info!("API URL: {}", url);
+    }
+    if let Some(ref team) = cli.team {
+        tracing::info!("Team: {}", team);
+    }
+
+    let retry_config = retry::RetryConfig {
+        max_retries: cli.max_retries,
+        retry_timeout: Duration::from_secs(cli.retry_timeout),
+        ..retry::RetryConfig::default()
+    };
 
-    let client = HoneycombClient::new(management_key, config_key, api_url);
+    let mut builder = HoneycombClient::builder()
+        .management_key(management_key)
+        .config_key(config_key)
+        .environment_keys(
+            profile
+                .map(|profile| profile.environment_key_values())
+                .unwrap_or_default(),
+        )
+        .base_url(api_url)
+        .timeout(cli.timeout.map(Duration::from_secs))
+        .connect_timeout(cli.connect_timeout.map(Duration::from_secs))
+        .proxy(cli.proxy)
+        .ca_bundle(cli.ca_bundle)
+        .client_identity(cli.client_cert, cli.client_key)
+        .user_agent(cli.user_agent)
+        .retry_config(retry_config)
+        .config_rate_limit(rate_limit::RateLimitConfig::new(
+            cli.config_rate_limit,
+            cli.config_max_concurrency,
+        ))
+        .management_rate_limit(rate_limit::RateLimitConfig::new(
+            cli.management_rate_limit,
+            cli.management_max_concurrency,
+        ))
+        .dry_run(cli.dry_run)
+        .record_cassette(cli.record);
+    if let Some(dir) = cache_dir {
+        // Without caching for this run, writes still clear what others cached
+        let ttl = Duration::from_secs(cli.cache_ttl.unwrap_or_default());
+        builder = builder.cache(Some(
+            cache::ResponseCache::new(dir, ttl)
+                .scope(cli.team.clone(), cli.environment.clone())
+                .bypass(cli.no_cache || cli.cache_ttl.is_none()),
+        ));
+    }
+    if let Some(path) = cli.replay {
+        let replay = cassette::ReplayTransport::from_file(&path)
+            .map_err(|e| errors::ApiaryError::ConfigError(format!("{:#}", e)))?;
+        builder = builder.transport(Arc::new(replay));
+    }
+    let client = builder.build()?;
 
-    let context = common::CommandContext { team: cli.team };
+    let context = common::CommandContext {
+        team: cli.team,
+        environment: cli.environment,
+    };
 
-    match cli.command {
+    let result = match cli.command {
         Some(command) => execute_command(&client, command, &context).await,
         None => {
             display_resource_usage();
             Ok(())
         }
+    };
+    if client.is_dry_run() {
+        eprintln!(
+            "Dry run: {} request(s) printed, not sent",
+            client.dry_run_count()
+        );
     }
+    result
 }
 
 fn display_resource_usage() {
@@ -229,17 +585,25 @@ fn display_resource_usage() {
     println!("Apiary - The Honeycomb API CLI");
     println!();
 
+    println!("  api                 - Raw request to any endpoint (keys chosen by path)");
+    println!("  api-keys            - Ingest and configuration key management (v2 Management API)");
     println!("  auth                - Authentication operations and token validation");
     println!("  boards              - Dashboard and board management");
     println!("  burn-alerts         - SLO burn alert configuration");
+    println!("  cache               - Local response cache (apiary cache clear)");
     println!("  calculated-fields   - Derived column calculations");
     println!("  columns             - Column definitions and metadata");
+    println!("  config              - Named profiles (list, get, set, use, current, env)");
     println!("  dataset-definitions - Dataset schema definitions");
     println!("  datasets            - Dataset management and configuration");
     println!("  environments        - Environment management (v2 Management API)");
+    println!("  events              - Send events to a dataset");
     println!("  marker-settings     - Marker display configuration");
     println!("  markers             - Event marker management");
+    println!("  queries             - Query creation and retrieval");
+    println!("  query-annotations   - Saved query names and descriptions");
     println!("  recipients          - Notification recipient management");
+    println!("  service-map         - Service dependency graph (table, DOT or Mermaid)");
     println!("  slos                - Service Level Objective management");
     println!("  triggers            - Alert trigger configuration");
     println!();
@@ -287,16 +651,25 @@ async fn execute_command(
     context: &common::CommandContext,
 ) -> Result<()> {
     match command {
+        Commands::Api(args) => args.execute(client, context).await,
+        Commands::Cache { .. } | Commands::Config { .. } => {
+            unreachable!("local commands run before the client is built")
+        }
+        Commands::ApiKeys { command } => command.execute(client, context).await,
         Commands::Auth { command } => command.execute(client, context).await,
         Commands::Datasets { command } => command.execute(client, context).await,
         Commands::Columns { command } => command.execute(client, context).await,
         Commands::Triggers { command } => command.execute(client, context).await,
         Commands::Boards { command } => command.execute(client, context).await,
         Commands::Markers { command } => command.execute(client, context).await,
+        Commands::Queries { command } => command.execute(client, context).await,
+        Commands::QueryAnnotations { command } => command.execute(client, context).await,
         Commands::Recipients { command } => command.execute(client, context).await,
+        Commands::ServiceMap(args) => args.execute(client, context).await,
         Commands::Slos { command } => command.execute(client, context).await,
         Commands::BurnAlerts { command } => command.execute(client, context).await,
         Commands::Environments { command } => command.execute(client, context).await,
+        Commands::Events { command } => command.execute(client, context).await,
         Commands::CalculatedFields { command } => command.execute(client, context).await,
         Commands::DatasetDefinitions { command } => command.execute(client, context).await,
         Commands::MarkerSettings { command } => command.execute(client, context).await,
@@ -1,7 +1,7 @@
 use crate::client::HoneycombClient;
 use crate::common::{
-    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, pretty_print_json,
-    read_json_file,
+    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, environment_client,
+    pretty_print_json, read_json_file,
 };
 use anyhow::Result;
 use clap::Subcommand;
@@ -16,7 +16,7 @@ pub enum MarkerCommands {
         #[arg(short, long)]
         dataset: String,
         /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
-        #[arg(short, long, env = "HONEYCOMB_ENVIRONMENT")]
+        #[arg(short, long)]
         environment: Option<String>,
         /// Output format
         #[arg(short, long, default_value = DEFAULT_TABLE_FORMAT)]
@@ -27,6 +27,9 @@ pub enum MarkerCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Marker data (JSON file path or inline JSON)
         #[arg(long)]
         data: String,
@@ -39,6 +42,9 @@ pub enum MarkerCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Marker ID
         #[arg(short, long)]
         id: String,
@@ -54,6 +60,9 @@ pub enum MarkerCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Marker ID
         #[arg(short, long)]
         id: String,
@@ -72,25 +81,36 @@ pub struct Marker {
 }
 
 impl MarkerCommands {
-    pub async fn execute(&self, client: &HoneycombClient, _context: &CommandContext) -> Result<()> {
+    fn environment(&self) -> Option<&str> {
+        match self {
+            MarkerCommands::List { environment, .. }
+            | MarkerCommands::Create { environment, .. }
+            | MarkerCommands::Update { environment, .. }
+            | MarkerCommands::Delete { environment, .. } => environment.as_deref(),
+        }
+    }
+
+    pub async fn execute(&self, client: &HoneycombClient, context: &CommandContext) -> Result<()> {
+        let client = &environment_client(client, context, self.environment()).await?;
+
         match self {
             MarkerCommands::List {
-                dataset,
-                environment,
-                format,
-            } => list_markers(client, dataset, environment.as_deref(), format).await,
+                dataset, format, ..
+            } => list_markers(client, dataset, format).await,
             MarkerCommands::Create {
                 dataset,
                 data,
                 format,
+                ..
             } => create_marker(client, dataset, data, format).await,
             MarkerCommands::Update {
                 dataset,
                 id,
                 data,
                 format,
+                ..
             } => update_marker(client, dataset, id, data, format).await,
-            MarkerCommands::Delete { dataset, id } => delete_marker(client, dataset, id).await,
+            MarkerCommands::Delete { dataset, id, .. } => delete_marker(client, dataset, id).await,
         }
     }
 }
@@ -98,36 +118,10 @@ impl MarkerCommands {
 async fn list_markers(
     client: &HoneycombClient,
     dataset: &str,
-    environment: Option<&str>,
     format: &OutputFormat,
 ) -> Result<()> {
-    use crate::common::require_valid_environment;
-    use std::collections::HashMap;
-
-    // If environment is provided, validate it exists
-    if let Some(env) = environment {
-        let team = std::env::var("HONEYCOMB_TEAM").unwrap_or_else(|_| "default".to_string());
-        require_valid_environment(client, &team, env).await?;
-    }
-
     let path = format!("/1/markers/{}", dataset);
-
-    // Add environment as query parameter if provided
-    let mut query_params = HashMap::new();
-    if let Some(env) = environment {
-        query_params.insert("environment".to_string(), env.to_string());
-    }
-
-    let response = client
-        .get(
-            &path,
-            if query_params.is_empty() {
-                None
-            } else {
-                Some(&query_params)
-            },
-        )
-        .await?;
+    let response = client.get(&path, None).await?;
 
     match format {
         OutputFormat::Json => {
@@ -179,6 +173,9 @@ async fn create_marker(
 
     let path = format!("/1/markers/{}", dataset);
     let response = client.post(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -207,6 +204,9 @@ async fn update_marker(
 
     let path = format!("/1/markers/{}/{}", dataset, id);
     let response = client.put(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -223,6 +223,9 @@ async fn update_marker(
 async fn delete_marker(client: &HoneycombClient, dataset: &str, id: &str) -> Result<()> {
     let path = format!("/1/markers/{}/{}", dataset, id);
     client.delete(&path).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     println!(
         "Marker '{}' in dataset '{}' deleted successfully",
@@ -0,0 +1,232 @@
+use crate::client::HoneycombClient;
+use crate::common::OutputFormat;
+use anyhow::{Context, Result};
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io::Write;
+
+/// Page size requested from JSON:API list endpoints (the v2 API maximum)
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+
+/// Flags shared by list commands backed by paginated endpoints
+#[derive(Args, Debug, Clone, Default)]
+pub struct PageArgs {
+    /// Maximum number of items to return
+    #[arg(long, conflicts_with = "all")]
+    pub limit: Option<usize>,
+    /// Fetch every page instead of only the first one
+    #[arg(long)]
+    pub all: bool,
+}
+
+impl PageArgs {
+    /// Fetch every page with no item limit
+    pub fn all() -> Self {
+        Self {
+            limit: None,
+            all: true,
+        }
+    }
+}
+
+/// One page of a v2 JSON:API list response
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ListResponse {
+    #[serde(default)]
+    pub data: Vec<Value>,
+    pub links: Option<PageLinks>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct PageLinks {
+    pub next: Option<String>,
+}
+
+/// Streams the pages of a v2 JSON:API list endpoint by following `links.next`
+pub struct Pages<'a> {
+    client: &'a HoneycombClient,
+    next: Option<String>,
+    query_params: Option<HashMap<String, String>>,
+    remaining: Option<usize>,
+    follow_next: bool,
+    has_more: bool,
+}
+
+impl<'a> Pages<'a> {
+    pub fn new(
+        client: &'a HoneycombClient,
+        path: &str,
+        mut query_params: HashMap<String, String>,
+        args: &PageArgs,
+    ) -> Self {
+        let page_size = args
+            .limit
+            .map_or(DEFAULT_PAGE_SIZE, |limit| limit.clamp(1, DEFAULT_PAGE_SIZE));
+        query_params.insert("page[size]".to_string(), page_size.to_string());
+
+        Self {
+            client,
+            next: Some(path.to_string()),
+            query_params: Some(query_params),
+            remaining: args.limit,
+            follow_next: args.all || args.limit.is_some(),
+            has_more: false,
+        }
+    }
+
+    /// Fetch the next page of `data` items, or `None` once the listing is exhausted
+    pub async fn next_page(&mut self) -> Result<Option<Vec<Value>>> {
+        Ok(self.next_response().await?.map(|(_, items)| items))
+    }
+
+    /// Like [`next_page`](Self::next_page), also returning the response document
+    async fn next_response(&mut self) -> Result<Option<(Value, Vec<Value>)>> {
+        if self.remaining == Some(0) {
+            return Ok(None);
+        }
+        let Some(path) = self.next.take() else {
+            return Ok(None);
+        };
+
+        // Only the first request carries our query parameters; next links already include them
+        let query_params = self.query_params.take();
+        let response = self.client.get(&path, query_params.as_ref()).await?;
+        let page: ListResponse = serde_json::from_value(response.clone())
+            .with_context(|| format!("Unexpected list response from {}", path))?;
+
+        let mut items = page.data;
+        let next = page
+            .links
+            .and_then(|links| links.next)
+            .map(|next| next_link_path(&next));
+
+        if let Some(remaining) = self.remaining.as_mut() {
+            if items.len() > *remaining {
+                items.truncate(*remaining);
+                self.has_more = true;
+            }
+            *remaining -= items.len();
+        }
+
+        if self.follow_next && !items.is_empty() {
+            self.next = next;
+        } else {
+            self.has_more |= next.is_some();
+        }
+        if self.remaining == Some(0) && self.next.is_some() {
+            self.has_more = true;
+        }
+
+        Ok(Some((response, items)))
+    }
+
+    /// Whether the listing stopped before the server ran out of results
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+
+    /// Collect every remaining item into memory
+    pub async fn collect(mut self) -> Result<Vec<Value>> {
+        let mut all = Vec::new();
+        while let Some(items) = self.next_page().await? {
+            all.extend(items);
+        }
+        Ok(all)
+    }
+}
+
+/// Turn a `links.next` value (absolute URL or path) into a request path
+fn next_link_path(next: &str) -> String {
+    match reqwest::Url::parse(next) {
+        Ok(url) => match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        },
+        Err(_) => next.to_string(),
+    }
+}
+
+/// Print a listing as JSON. A single page is printed as the API returned it,
+/// `links` and `meta` included; with `--limit` or `--all` the pages are
+/// streamed into one `{"data": [...]}` document.
+pub async fn write_json_list(pages: &mut Pages<'_>, format: &OutputFormat) -> Result<()> {
+    if !pages.follow_next {
+        if let Some((response, _)) = pages.next_response().await? {
+            match format {
+                OutputFormat::Pretty => println!("{}", serde_json::to_string_pretty(&response)?),
+                _ => println!("{}", serde_json::to_string(&response)?),
+            }
+        }
+        return Ok(());
+    }
+
+    let mut writer = JsonListWriter::new(format);
+    while let Some(items) = pages.next_page().await? {
+        writer.write_items(&items)?;
+    }
+    writer.finish()
+}
+
+/// Writes list items as a `{"data": [...]}` document one item at a time
+struct JsonListWriter {
+    pretty: bool,
+    count: usize,
+}
+
+impl JsonListWriter {
+    fn new(format: &OutputFormat) -> Self {
+        Self {
+            pretty: matches!(format, OutputFormat::Pretty),
+            count: 0,
+        }
+    }
+
+    fn write_items(&mut self, items: &[Value]) -> Result<()> {
+        let mut out = std::io::stdout().lock();
+        for item in items {
+            let separator = if self.count == 0 { "" } else { "," };
+            if self.count == 0 {
+                write!(
+                    out,
+                    "{}",
+                    if self.pretty {
+                        "{\n  \"data\": ["
+                    } else {
+                        "{\"data\":["
+                    }
+                )?;
+            }
+            if self.pretty {
+                let body = serde_json::to_string_pretty(item)?.replace('\n', "\n    ");
+                write!(out, "{}\n    {}", separator, body)?;
+            } else {
+                write!(out, "{}{}", separator, serde_json::to_string(item)?)?;
+            }
+            self.count += 1;
+        }
+        out.flush()?;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<()> {
+        let closing = match (self.count, self.pretty) {
+            (0, true) => "{\n  \"data\": []\n}",
+            (0, false) => "{\"data\":[]}",
+            (_, true) => "\n  ]\n}",
+            (_, false) => "]}",
+        };
+        println!("{}", closing);
+        Ok(())
+    }
+}
+
+/// Tell the user that a listing was cut short
+pub fn print_more_hint(pages: &Pages) {
+    if pages.has_more() {
+        eprintln!(
+            "More results available. Use --all to fetch every page or --limit to fetch more."
+        );
+    }
+}
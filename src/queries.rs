@@ -0,0 +1,428 @@
+use crate::client::HoneycombClient;
+use crate::common::{
+    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, environment_client,
+    pretty_print_json, read_json_file,
+};
+use crate::errors::ApiaryError;
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use std::time::{Duration, Instant};
+
+#[derive(Subcommand)]
+pub enum QueryCommands {
+    /// Create a new query
+    Create {
+        /// Dataset slug (use __all__ for environment-wide queries)
+        #[arg(short, long)]
+        dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
+        /// Query specification (JSON file path or inline JSON)
+        #[arg(long)]
+        data: String,
+        /// Output format
+        #[arg(short, long, default_value = DEFAULT_PRETTY_FORMAT)]
+        format: OutputFormat,
+    },
+    /// Get a specific query
+    Get {
+        /// Dataset slug (use __all__ for environment-wide queries)
+        #[arg(short, long)]
+        dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
+        /// Query ID
+        #[arg(short, long)]
+        id: String,
+        /// Output format
+        #[arg(short, long, default_value = DEFAULT_PRETTY_FORMAT)]
+        format: OutputFormat,
+    },
+    /// Create a query, run it and wait for the results
+    Run {
+        /// Dataset slug (use __all__ for environment-wide queries)
+        #[arg(short, long)]
+        dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
+        /// Query specification (JSON file path or inline JSON)
+        #[arg(long)]
+        data: String,
+        /// Maximum number of seconds to wait for results
+        #[arg(long, default_value_t = 60)]
+        timeout: u64,
+        /// Milliseconds to wait between polls for results
+        #[arg(long, default_value_t = 1000)]
+        poll_interval: u64,
+        /// Output format
+        #[arg(short, long, default_value = DEFAULT_TABLE_FORMAT)]
+        format: OutputFormat,
+    },
+}
+
+/// Query specification as accepted by the Queries API
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct QuerySpec {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub breakdowns: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub calculations: Vec<QueryCalculation>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub filters: Vec<QueryFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_combination: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub granularity: Option<i64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub orders: Vec<QueryOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_range: Option<i64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub havings: Vec<QueryHaving>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct QueryCalculation {
+    pub op: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct QueryFilter {
+    pub column: String,
+    pub op: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct QueryOrder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub op: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct QueryHaving {
+    pub calculate_op: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+    pub op: String,
+    pub value: f64,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct QueryResult {
+    pub id: String,
+    pub complete: bool,
+    pub data: Option<QueryResultData>,
+    pub links: Option<Value>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct QueryResultData {
+    #[serde(default)]
+    pub series: Vec<QueryResultRow>,
+    #[serde(default)]
+    pub results: Vec<QueryResultRow>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct QueryResultRow {
+    pub time: Option<String>,
+    #[serde(default)]
+    pub data: Map<String, Value>,
+}
+
+impl QueryCalculation {
+    /// Column name the API uses for this calculation in result rows
+    pub fn result_key(&self) -> String {
+        match &self.column {
+            Some(column) => format!("{}({})", self.op, column),
+            None => self.op.clone(),
+        }
+    }
+}
+
+impl QueryCommands {
+    fn environment(&self) -> Option<&str> {
+        match self {
+            QueryCommands::Create { environment, .. }
+            | QueryCommands::Get { environment, .. }
+            | QueryCommands::Run { environment, .. } => environment.as_deref(),
+        }
+    }
+
+    pub async fn execute(&self, client: &HoneycombClient, context: &CommandContext) -> Result<()> {
+        let client = &environment_client(client, context, self.environment()).await?;
+
+        match self {
+            QueryCommands::Create {
+                dataset,
+                data,
+                format,
+                ..
+            } => create_query(client, dataset, data, format).await,
+            QueryCommands::Get {
+                dataset,
+                id,
+                format,
+                ..
+            } => get_query(client, dataset, id, format).await,
+            QueryCommands::Run {
+                dataset,
+                data,
+                timeout,
+                poll_interval,
+                format,
+                ..
+            } => {
+                run_query(
+                    client,
+                    dataset,
+                    data,
+                    Duration::from_secs(*timeout),
+                    Duration::from_millis(*poll_interval),
+                    format,
+                )
+                .await
+            }
+        }
+    }
+}
+
+/// Parse a query specification from a JSON file path or inline JSON
+pub fn parse_query_spec(data: &str) -> Result<QuerySpec> {
+    let json_data = if std::path::Path::new(data).exists() {
+        read_json_file(data)?
+    } else {
+        serde_json::from_str(data)?
+    };
+
+    serde_json::from_value(json_data).map_err(|e| {
+        ApiaryError::ValidationError(format!("Invalid query specification: {}", e)).into()
+    })
+}
+
+/// Create a query and return the response from the API
+pub async fn create_query_spec(
+    client: &HoneycombClient,
+    dataset: &str,
+    spec: &QuerySpec,
+) -> Result<Value> {
+    let path = format!("/1/queries/{}", dataset);
+    client.post(&path, &serde_json::to_value(spec)?).await
+}
+
+async fn create_query(
+    client: &HoneycombClient,
+    dataset: &str,
+    data: &str,
+    format: &OutputFormat,
+) -> Result<()> {
+    let spec = parse_query_spec(data)?;
+    let response = create_query_spec(client, dataset, &spec).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(&response)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!("{}", pretty_print_json(&response)?);
+        }
+    }
+
+    Ok(())
+}
+
+async fn get_query(
+    client: &HoneycombClient,
+    dataset: &str,
+    id: &str,
+    format: &OutputFormat,
+) -> Result<()> {
+    let path = format!("/1/queries/{}/{}", dataset, id);
+    let response = client.get(&path, None).await?;
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(&response)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!("{}", pretty_print_json(&response)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Start a query result for an existing query and poll until it is complete
+pub async fn fetch_query_result(
+    client: &HoneycombClient,
+    dataset: &str,
+    query_id: &str,
+    timeout: Duration,
+    poll_interval: Duration,
+) -> Result<QueryResult> {
+    let path = format!("/1/query_results/{}", dataset);
+    let body = json!({ "query_id": query_id, "disable_series": false });
+    let response = client.post(&path, &body).await?;
+    let mut result: QueryResult =
+        serde_json::from_value(response).context("Unexpected query result response")?;
+
+    let deadline = Instant::now() + timeout;
+    while !result.complete {
+        if Instant::now() >= deadline {
+            anyhow::bail!(
+                "Timed out after {}s waiting for query result '{}' in dataset '{}'",
+                timeout.as_secs(),
+                result.id,
+                dataset
+            );
+        }
+        tokio::time::sleep(poll_interval).await;
+
+        let path = format!("/1/query_results/{}/{}", dataset, result.id);
+        let response = client.get(&path, None).await?;
+        result = serde_json::from_value(response).context("Unexpected query result response")?;
+    }
+
+    Ok(result)
+}
+
+async fn run_query(
+    client: &HoneycombClient,
+    dataset: &str,
+    data: &str,
+    timeout: Duration,
+    poll_interval: Duration,
+    format: &OutputFormat,
+) -> Result<()> {
+    let spec = parse_query_spec(data)?;
+    let query = create_query_spec(client, dataset, &spec).await?;
+    // Without a saved query there is no result to fetch
+    if client.is_dry_run() {
+        return Ok(());
+    }
+    let query_id = query
+        .get("id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| anyhow::anyhow!("Query response did not include an ID"))?;
+
+    let result = fetch_query_result(client, dataset, query_id, timeout, poll_interval).await?;
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(&result)?);
+        }
+        OutputFormat::Pretty => {
+            println!("{}", serde_json::to_string_pretty(&result)?);
+        }
+        OutputFormat::Table => print_result_table(&spec, &result),
+    }
+
+    Ok(())
+}
+
+/// Render query results as one row per time bucket and breakdown group
+fn print_result_table(spec: &QuerySpec, result: &QueryResult) {
+    let data = result.data.as_ref();
+    let (rows, timed) = match data {
+        Some(d) if !d.series.is_empty() => (&d.series, true),
+        Some(d) => (&d.results, false),
+        None => {
+            println!("No results");
+            return;
+        }
+    };
+
+    let calculations: Vec<String> = if spec.calculations.is_empty() {
+        vec!["COUNT".to_string()]
+    } else {
+        spec.calculations
+            .iter()
+            .map(QueryCalculation::result_key)
+            .collect()
+    };
+
+    let mut headers: Vec<String> = Vec::new();
+    if timed {
+        headers.push("Time".to_string());
+    }
+    headers.extend(spec.breakdowns.iter().cloned());
+    headers.extend(calculations.iter().cloned());
+
+    let table: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            let mut cells = Vec::with_capacity(headers.len());
+            if timed {
+                cells.push(row.time.clone().unwrap_or_default());
+            }
+            for key in spec.breakdowns.iter().chain(calculations.iter()) {
+                cells.push(format_cell(row.data.get(key)));
+            }
+            cells
+        })
+        .collect();
+
+    let widths: Vec<usize> = headers
+        .iter()
+        .enumerate()
+        .map(|(i, h)| {
+            table
+                .iter()
+                .map(|r| r[i].len())
+                .chain(std::iter::once(h.len()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let line = |cells: &[String]| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(c, w)| format!("{:<width$}", c, width = w))
+            .collect::<Vec<_>>()
+            .join("  ")
+    };
+
+    println!("{}", line(&headers));
+    println!(
+        "{:-<1$}",
+        "",
+        widths.iter().sum::<usize>() + 2 * (widths.len() - 1)
+    );
+    for row in &table {
+        println!("{}", line(row));
+    }
+}
+
+fn format_cell(value: Option<&Value>) -> String {
+    match value {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Null) | None => "-".to_string(),
+        Some(v) => v.to_string(),
+    }
+}
@@ -0,0 +1,275 @@
+use crate::client::HoneycombClient;
+use crate::common::{
+    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, environment_client,
+    pretty_print_json, read_json_file,
+};
+use anyhow::Result;
+use clap::Subcommand;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Subcommand)]
+pub enum QueryAnnotationCommands {
+    /// List all query annotations in a dataset
+    List {
+        /// Dataset slug
+        #[arg(short, long)]
+        dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
+        /// Output format
+        #[arg(short, long, default_value = DEFAULT_TABLE_FORMAT)]
+        format: OutputFormat,
+    },
+    /// Get a specific query annotation
+    Get {
+        /// Dataset slug
+        #[arg(short, long)]
+        dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
+        /// Query Annotation ID
+        #[arg(short, long)]
+        id: String,
+        /// Output format
+        #[arg(short, long, default_value = DEFAULT_PRETTY_FORMAT)]
+        format: OutputFormat,
+    },
+    /// Create a new query annotation
+    Create {
+        /// Dataset slug
+        #[arg(short, long)]
+        dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
+        /// Query annotation data (JSON file path or inline JSON)
+        #[arg(long)]
+        data: String,
+        /// Output format
+        #[arg(short, long, default_value = DEFAULT_PRETTY_FORMAT)]
+        format: OutputFormat,
+    },
+    /// Update a query annotation
+    Update {
+        /// Dataset slug
+        #[arg(short, long)]
+        dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
+        /// Query Annotation ID
+        #[arg(short, long)]
+        id: String,
+        /// Query annotation data (JSON file path or inline JSON)
+        #[arg(long)]
+        data: String,
+        /// Output format
+        #[arg(short, long, default_value = DEFAULT_PRETTY_FORMAT)]
+        format: OutputFormat,
+    },
+    /// Delete a query annotation
+    Delete {
+        /// Dataset slug
+        #[arg(short, long)]
+        dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
+        /// Query Annotation ID
+        #[arg(short, long)]
+        id: String,
+    },
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct QueryAnnotation {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub query_id: String,
+    pub source: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl QueryAnnotationCommands {
+    fn environment(&self) -> Option<&str> {
+        match self {
+            QueryAnnotationCommands::List { environment, .. }
+            | QueryAnnotationCommands::Get { environment, .. }
+            | QueryAnnotationCommands::Create { environment, .. }
+            | QueryAnnotationCommands::Update { environment, .. }
+            | QueryAnnotationCommands::Delete { environment, .. } => environment.as_deref(),
+        }
+    }
+
+    pub async fn execute(&self, client: &HoneycombClient, context: &CommandContext) -> Result<()> {
+        let client = &environment_client(client, context, self.environment()).await?;
+
+        match self {
+            QueryAnnotationCommands::List {
+                dataset, format, ..
+            } => list_query_annotations(client, dataset, format).await,
+            QueryAnnotationCommands::Get {
+                dataset,
+                id,
+                format,
+                ..
+            } => get_query_annotation(client, dataset, id, format).await,
+            QueryAnnotationCommands::Create {
+                dataset,
+                data,
+                format,
+                ..
+            } => create_query_annotation(client, dataset, data, format).await,
+            QueryAnnotationCommands::Update {
+                dataset,
+                id,
+                data,
+                format,
+                ..
+            } => update_query_annotation(client, dataset, id, data, format).await,
+            QueryAnnotationCommands::Delete { dataset, id, .. } => {
+                delete_query_annotation(client, dataset, id).await
+            }
+        }
+    }
+}
+
+async fn list_query_annotations(
+    client: &HoneycombClient,
+    dataset: &str,
+    format: &OutputFormat,
+) -> Result<()> {
+    let path = format!("/1/query_annotations/{}", dataset);
+    let response = client.get(&path, None).await?;
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(&response)?);
+        }
+        OutputFormat::Pretty => {
+            println!("{}", pretty_print_json(&response)?);
+        }
+        OutputFormat::Table => {
+            if let Value::Array(annotations) = response {
+                println!("{:<15} {:<40} {:<15} Created", "ID", "Name", "Query ID");
+                println!("{:-<85}", "");
+
+                for annotation in annotations {
+                    if let Ok(qa) = serde_json::from_value::<QueryAnnotation>(annotation) {
+                        println!(
+                            "{:<15} {:<40} {:<15} {}",
+                            qa.id,
+                            qa.name,
+                            qa.query_id,
+                            qa.created_at.format("%Y-%m-%d")
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn get_query_annotation(
+    client: &HoneycombClient,
+    dataset: &str,
+    id: &str,
+    format: &OutputFormat,
+) -> Result<()> {
+    let path = format!("/1/query_annotations/{}/{}", dataset, id);
+    let response = client.get(&path, None).await?;
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(&response)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!("{}", pretty_print_json(&response)?);
+        }
+    }
+
+    Ok(())
+}
+
+async fn create_query_annotation(
+    client: &HoneycombClient,
+    dataset: &str,
+    data: &str,
+    format: &OutputFormat,
+) -> Result<()> {
+    let json_data = if std::path::Path::new(data).exists() {
+        read_json_file(data)?
+    } else {
+        serde_json::from_str(data)?
+    };
+
+    let path = format!("/1/query_annotations/{}", dataset);
+    let response = client.post(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(&response)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!("{}", pretty_print_json(&response)?);
+        }
+    }
+
+    Ok(())
+}
+
+async fn update_query_annotation(
+    client: &HoneycombClient,
+    dataset: &str,
+    id: &str,
+    data: &str,
+    format: &OutputFormat,
+) -> Result<()> {
+    let json_data = if std::path::Path::new(data).exists() {
+        read_json_file(data)?
+    } else {
+        serde_json::from_str(data)?
+    };
+
+    let path = format!("/1/query_annotations/{}/{}", dataset, id);
+    let response = client.put(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(&response)?);
+        }
+        OutputFormat::Pretty | OutputFormat::Table => {
+            println!("{}", pretty_print_json(&response)?);
+        }
+    }
+
+    Ok(())
+}
+
+async fn delete_query_annotation(client: &HoneycombClient, dataset: &str, id: &str) -> Result<()> {
+    let path = format!("/1/query_annotations/{}/{}", dataset, id);
+    client.delete(&path).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
+
+    println!(
+        "Query annotation '{}' in dataset '{}' deleted successfully",
+        id, dataset
+    );
+
+    Ok(())
+}
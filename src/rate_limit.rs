@@ -0,0 +1,158 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore};
+use tokio::time::Instant;
+
+/// Default requests per second for the v1 configuration API
+pub const DEFAULT_CONFIG_RATE_LIMIT: f64 = 10.0;
+/// Default requests per second for the v2 management API
+pub const DEFAULT_MANAGEMENT_RATE_LIMIT: f64 = 5.0;
+/// Default number of requests in flight at once, per API family
+pub const DEFAULT_MAX_CONCURRENCY: usize = 4;
+
+/// Client-side limits for one API family (v1 configuration or v2 management)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimitConfig {
+    /// Sustained request rate; 0 disables rate limiting
+    pub requests_per_second: f64,
+    /// Requests that may be sent back to back before the rate applies
+    pub burst: u32,
+    /// Maximum number of requests in flight at once
+    pub max_concurrency: usize,
+}
+
+impl RateLimitConfig {
+    /// Limit to `requests_per_second`, allowing a burst of one second's worth of requests
+    pub fn new(requests_per_second: f64, max_concurrency: usize) -> Self {
+        Self {
+            requests_per_second,
+            burst: requests_per_second.ceil().max(1.0) as u32,
+            max_concurrency,
+        }
+    }
+
+    pub fn config_api() -> Self {
+        Self::new(DEFAULT_CONFIG_RATE_LIMIT, DEFAULT_MAX_CONCURRENCY)
+    }
+
+    pub fn management_api() -> Self {
+        Self::new(DEFAULT_MANAGEMENT_RATE_LIMIT, DEFAULT_MAX_CONCURRENCY)
+    }
+
+    /// Check the settings, describing the first problem found
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.requests_per_second.is_finite() || self.requests_per_second < 0.0 {
+            return Err(format!(
+                "Invalid rate limit {}: expected a non-negative number of requests per second",
+                self.requests_per_second
+            ));
+        }
+        if self.burst == 0 {
+            return Err("Rate limit burst must be at least 1".to_string());
+        }
+        if self.max_concurrency == 0 {
+            return Err("Maximum concurrency must be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Token bucket plus concurrency cap, shared by every clone of a client
+#[derive(Debug)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    bucket: Mutex<Bucket>,
+    in_flight: Arc<Semaphore>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Available tokens; negative when callers are already queued for future tokens
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+/// Held while a request is in flight; dropping it frees a concurrency slot
+#[derive(Debug)]
+pub struct Permit {
+    _slot: OwnedSemaphorePermit,
+    /// Time spent waiting for a slot and a token
+    pub waited: Duration,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            bucket: Mutex::new(Bucket {
+                tokens: config.burst as f64,
+                refilled_at: Instant::now(),
+            }),
+            in_flight: Arc::new(Semaphore::new(config.max_concurrency)),
+            config,
+        }
+    }
+
+    /// Wait until a request may be sent: first for a free concurrency slot,
+    /// then for a token from the bucket.
+    pub async fn acquire(&self) -> Permit {
+        let started = Instant::now();
+        let slot = self
+            .in_flight
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("rate limiter semaphore is never closed");
+
+        if let Some(wait) = self.reserve_token().await {
+            tokio::time::sleep(wait).await;
+        }
+
+        Permit {
+            _slot: slot,
+            waited: started.elapsed(),
+        }
+    }
+
+    /// Take a token, returning how long to wait if it is not available yet.
+    /// Tokens are reserved up front so concurrent callers queue fairly.
+    async fn reserve_token(&self) -> Option<Duration> {
+        let rate = self.config.requests_per_second;
+        if rate == 0.0 {
+            return None;
+        }
+
+        let mut bucket = self.bucket.lock().await;
+        let now = Instant::now();
+        let refill = now.duration_since(bucket.refilled_at).as_secs_f64() * rate;
+        bucket.tokens = (bucket.tokens + refill).min(self.config.burst as f64);
+        bucket.refilled_at = now;
+        bucket.tokens -= 1.0;
+
+        (bucket.tokens < 0.0).then(|| Duration::from_secs_f64(-bucket.tokens / rate))
+    }
+}
+
+/// Separate limiters for the v1 configuration and v2 management APIs
+#[derive(Debug)]
+pub struct ApiLimiters {
+    pub config: RateLimiter,
+    pub management: RateLimiter,
+}
+
+impl ApiLimiters {
+    pub fn new(config: RateLimitConfig, management: RateLimitConfig) -> Self {
+        Self {
+            config: RateLimiter::new(config),
+            management: RateLimiter::new(management),
+        }
+    }
+
+    /// Limiter for a request path (`/2/...` paths use the management API limits)
+    pub fn for_path(&self, path: &str) -> &RateLimiter {
+        if path.starts_with("/2/") {
+            &self.management
+        } else {
+            &self.config
+        }
+    }
+}
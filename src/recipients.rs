@@ -147,6 +147,9 @@ async fn create_recipient(
     };
 
     let response = client.post("/1/recipients", &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -174,6 +177,9 @@ async fn update_recipient(
 
     let path = format!("/1/recipients/{}", id);
     let response = client.put(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -190,6 +196,9 @@ async fn update_recipient(
 async fn delete_recipient(client: &HoneycombClient, id: &str) -> Result<()> {
     let path = format!("/1/recipients/{}", id);
     client.delete(&path).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     println!("Recipient '{}' deleted successfully", id);
 
@@ -0,0 +1,135 @@
+use crate::client::HoneycombClient;
+use crate::errors::ApiaryError;
+use serde::Serialize;
+use std::fmt;
+
+/// Honeycomb region, each with its own API host
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Region {
+    Us,
+    Eu,
+}
+
+impl Region {
+    pub const ALL: [Region; 2] = [Region::Us, Region::Eu];
+
+    pub fn api_url(self) -> &'static str {
+        match self {
+            Region::Us => "https://api.honeycomb.io",
+            Region::Eu => "https://api.eu1.honeycomb.io",
+        }
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Region::Us => write!(f, "us"),
+            Region::Eu => write!(f, "eu"),
+        }
+    }
+}
+
+/// API base URL from `--api-url`, `--region` or `--api-endpoint`, in that order.
+/// Callers drop values shadowed by a command line flag before calling this.
+pub fn resolve_api_url(
+    api_url: Option<String>,
+    region: Option<Region>,
+    api_endpoint: Option<String>,
+) -> Option<String> {
+    api_url
+        .or_else(|| region.map(|region| region.api_url().to_string()))
+        .or_else(|| {
+            api_endpoint.map(|endpoint| {
+                if endpoint.starts_with("http://") || endpoint.starts_with("https://") {
+                    endpoint
+                } else {
+                    format!("https://{}", endpoint)
+                }
+            })
+        })
+}
+
+/// Outcome of checking the configured keys against one API host
+#[derive(Serialize, Debug)]
+pub struct RegionProbe {
+    pub region: String,
+    pub api_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub management: Option<KeyProbe>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configuration: Option<KeyProbe>,
+}
+
+impl RegionProbe {
+    /// Whether every configured key was accepted
+    pub fn accepted(&self) -> bool {
+        [&self.management, &self.configuration]
+            .into_iter()
+            .flatten()
+            .all(|probe| *probe == KeyProbe::Accepted)
+    }
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyProbe {
+    Accepted,
+    /// The host answered 401 or 403
+    Rejected,
+    /// Any other failure, e.g. a network error
+    Failed(String),
+}
+
+impl fmt::Display for KeyProbe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyProbe::Accepted => write!(f, "accepted"),
+            KeyProbe::Rejected => write!(f, "rejected"),
+            KeyProbe::Failed(reason) => write!(f, "failed: {}", reason),
+        }
+    }
+}
+
+/// Call the auth endpoints with the configured keys on each `(region, api_url)`.
+///
+/// Hosts are probed one after another; a key that works in one region is
+/// rejected by the other, so the results point at the right `--region`.
+pub async fn probe_regions(
+    client: &HoneycombClient,
+    candidates: &[(String, String)],
+) -> Vec<RegionProbe> {
+    let mut probes = Vec::new();
+    for (region, api_url) in candidates {
+        let regional = client.with_base_url(api_url.clone());
+        let management = if client.has_management_key() {
+            Some(probe_key(&regional, "/2/auth").await)
+        } else {
+            None
+        };
+        let configuration = if client.has_config_key() {
+            Some(probe_key(&regional, "/1/auth").await)
+        } else {
+            None
+        };
+        probes.push(RegionProbe {
+            region: region.clone(),
+            api_url: api_url.clone(),
+            management,
+            configuration,
+        });
+    }
+    probes
+}
+
+async fn probe_key(client: &HoneycombClient, path: &str) -> KeyProbe {
+    match client.get(path, None).await {
+        Ok(_) => KeyProbe::Accepted,
+        Err(err) => match err.chain().find_map(|e| e.downcast_ref::<ApiaryError>()) {
+            Some(ApiaryError::ApiError {
+                status: 401 | 403, ..
+            }) => KeyProbe::Rejected,
+            _ => KeyProbe::Failed(format!("{:#}", err)),
+        },
+    }
+}
@@ -0,0 +1,89 @@
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Method, StatusCode};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Default number of retries after the initial attempt
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+/// Default upper bound on the total time spent retrying, in seconds
+pub const DEFAULT_RETRY_TIMEOUT_SECS: u64 = 30;
+
+/// Retry policy for transient API failures (429 and 5xx responses, connection errors)
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// Maximum number of retries after the first attempt (0 disables retries)
+    pub max_retries: u32,
+    /// Give up once this much time has been spent on a request, including waits
+    pub retry_timeout: Duration,
+    /// Delay before the first retry; doubled on each subsequent retry
+    pub base_delay: Duration,
+    /// Upper bound for a single backoff delay
+    pub max_delay: Duration,
+    /// Also retry POST and PATCH requests, which may not be safe to repeat
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            retry_timeout: Duration::from_secs(DEFAULT_RETRY_TIMEOUT_SECS),
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(10),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Whether requests with this method may be retried under this policy
+    pub fn allows_method(&self, method: &Method) -> bool {
+        self.retry_non_idempotent || is_idempotent(method)
+    }
+
+    /// Jittered exponential backoff for the given retry attempt (starting at 0)
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        // "Equal jitter": wait at least half the exponential delay
+        let half = exp / 2;
+        half + half.mul_f64(random_fraction())
+    }
+}
+
+pub fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+    )
+}
+
+pub fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Parse a `Retry-After` header given either as seconds or an HTTP date
+pub fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let wait = date.with_timezone(&chrono::Utc) - chrono::Utc::now();
+    Some(wait.to_std().unwrap_or(Duration::ZERO))
+}
+
+/// A random value in [0, 1) without pulling in an RNG dependency
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
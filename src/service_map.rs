@@ -0,0 +1,215 @@
+use crate::client::HoneycombClient;
+use crate::common::{CommandContext, DEFAULT_TABLE_FORMAT, OutputFormat, environment_client};
+use anyhow::{Context, Result};
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+#[derive(Args)]
+pub struct ServiceMapArgs {
+    /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+    #[arg(short, long)]
+    pub environment: Option<String>,
+    /// Relative time range in seconds (ignored when --start-time is set)
+    #[arg(long, default_value_t = 7200)]
+    pub time_range: i64,
+    /// Start of the time window (Unix timestamp)
+    #[arg(long)]
+    pub start_time: Option<i64>,
+    /// End of the time window (Unix timestamp)
+    #[arg(long, requires = "start_time")]
+    pub end_time: Option<i64>,
+    /// Only include dependencies of these services (repeat the flag or separate with commas)
+    #[arg(short, long, value_delimiter = ',')]
+    pub service: Vec<String>,
+    /// Maximum number of dependencies to return
+    #[arg(long)]
+    pub limit: Option<u32>,
+    /// Maximum number of seconds to wait for the map
+    #[arg(long, default_value_t = 60)]
+    pub timeout: u64,
+    /// Milliseconds to wait between polls
+    #[arg(long, default_value_t = 1000)]
+    pub poll_interval: u64,
+    /// Output format
+    #[arg(short, long, value_enum, default_value = DEFAULT_TABLE_FORMAT)]
+    pub format: OutputFormat,
+    /// Render the map as a graph instead (overrides --format)
+    #[arg(long, value_enum)]
+    pub graph: Option<GraphFormat>,
+}
+
+/// Graph languages supported by `service-map --graph`
+#[derive(Debug, Clone, clap::ValueEnum)]
+pub enum GraphFormat {
+    Dot,
+    Mermaid,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct MapRequest {
+    pub request_id: String,
+    pub status: String,
+    #[serde(default)]
+    pub dependencies: Vec<MapDependency>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct MapDependency {
+    pub parent_node: MapNode,
+    pub child_node: MapNode,
+    pub call_count: u64,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct MapNode {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub node_type: String,
+}
+
+impl ServiceMapArgs {
+    pub async fn execute(&self, client: &HoneycombClient, context: &CommandContext) -> Result<()> {
+        let client = &environment_client(client, context, self.environment.as_deref()).await?;
+
+        let mut body = json!({});
+        if let Some(start_time) = self.start_time {
+            body["start_time"] = json!(start_time);
+            if let Some(end_time) = self.end_time {
+                body["end_time"] = json!(end_time);
+            }
+        } else {
+            body["time_range"] = json!(self.time_range);
+        }
+        if !self.service.is_empty() {
+            body["filters"] = self
+                .service
+                .iter()
+                .map(|name| json!({ "name": name, "type": "service" }))
+                .collect();
+        }
+        if let Some(limit) = self.limit {
+            body["limit"] = json!(limit);
+        }
+
+        let map = fetch_dependencies(
+            client,
+            &body,
+            Duration::from_secs(self.timeout),
+            Duration::from_millis(self.poll_interval),
+        )
+        .await?;
+
+        match (&self.graph, &self.format) {
+            (Some(GraphFormat::Dot), _) => print!("{}", render_dot(&map.dependencies)),
+            (Some(GraphFormat::Mermaid), _) => print!("{}", render_mermaid(&map.dependencies)),
+            (None, OutputFormat::Json) => {
+                println!("{}", serde_json::to_string(&map.dependencies)?)
+            }
+            (None, OutputFormat::Pretty) => {
+                println!("{}", serde_json::to_string_pretty(&map.dependencies)?)
+            }
+            (None, OutputFormat::Table) => print_table(&map.dependencies),
+        }
+
+        Ok(())
+    }
+}
+
+/// Create a map dependencies request and poll until it is ready
+pub async fn fetch_dependencies(
+    client: &HoneycombClient,
+    body: &serde_json::Value,
+    timeout: Duration,
+    poll_interval: Duration,
+) -> Result<MapRequest> {
+    let response = client.post("/1/maps/dependencies/requests", body).await?;
+    let mut map: MapRequest =
+        serde_json::from_value(response).context("Unexpected map dependencies response")?;
+
+    let deadline = Instant::now() + timeout;
+    loop {
+        match map.status.as_str() {
+            "ready" => return Ok(map),
+            "error" => anyhow::bail!("Map dependencies request '{}' failed", map.request_id),
+            _ => {}
+        }
+        if Instant::now() >= deadline {
+            anyhow::bail!(
+                "Timed out after {}s waiting for map dependencies request '{}'",
+                timeout.as_secs(),
+                map.request_id
+            );
+        }
+        tokio::time::sleep(poll_interval).await;
+
+        let path = format!("/1/maps/dependencies/requests/{}", map.request_id);
+        let response = client.get(&path, None).await?;
+        map = serde_json::from_value(response).context("Unexpected map dependencies response")?;
+    }
+}
+
+fn print_table(dependencies: &[MapDependency]) {
+    println!("{:<35} {:<35} Calls", "Parent", "Child");
+    println!("{:-<80}", "");
+
+    for dep in dependencies {
+        println!(
+            "{:<35} {:<35} {}",
+            dep.parent_node.name, dep.child_node.name, dep.call_count
+        );
+    }
+}
+
+/// Render dependencies as a Graphviz DOT digraph
+pub fn render_dot(dependencies: &[MapDependency]) -> String {
+    let mut out = String::from("digraph service_map {\n    rankdir=LR;\n");
+    for dep in dependencies {
+        out.push_str(&format!(
+            "    \"{}\" -> \"{}\" [label=\"{}\"];\n",
+            dot_escape(&dep.parent_node.name),
+            dot_escape(&dep.child_node.name),
+            dep.call_count
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Render dependencies as a Mermaid flowchart.
+///
+/// Service names can't be used as node IDs (they may contain any character or
+/// be a Mermaid keyword such as `end`), so each service gets a generated ID and
+/// its name as a quoted label.
+pub fn render_mermaid(dependencies: &[MapDependency]) -> String {
+    let mut ids: HashMap<&str, String> = HashMap::new();
+    let mut nodes = String::new();
+    let mut edges = String::new();
+    for dep in dependencies {
+        let [parent, child] = [&dep.parent_node.name, &dep.child_node.name].map(|name| {
+            let next = ids.len();
+            ids.entry(name)
+                .or_insert_with(|| {
+                    let id = format!("n{}", next);
+                    nodes.push_str(&format!("    {}[\"{}\"]\n", id, mermaid_escape(name)));
+                    id
+                })
+                .clone()
+        });
+        edges.push_str(&format!(
+            "    {} -->|{}| {}\n",
+            parent, dep.call_count, child
+        ));
+    }
+    format!("flowchart LR\n{}{}", nodes, edges)
+}
+
+fn dot_escape(name: &str) -> String {
+    name.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn mermaid_escape(name: &str) -> String {
+    name.replace('"', "#quot;")
+}
@@ -1,12 +1,13 @@
 use crate::client::HoneycombClient;
 use crate::common::{
-    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, pretty_print_json,
-    read_json_file,
+    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, environment_client,
+    pretty_print_json, read_json_file,
 };
 use anyhow::Result;
 use clap::Subcommand;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Value, json};
+use std::collections::HashMap;
 
 #[derive(Subcommand)]
 pub enum SloCommands {
@@ -16,7 +17,7 @@ pub enum SloCommands {
         #[arg(short, long)]
         dataset: String,
         /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
-        #[arg(short, long, env = "HONEYCOMB_ENVIRONMENT")]
+        #[arg(short, long)]
         environment: Option<String>,
         /// Output format
         #[arg(short, long, default_value = DEFAULT_TABLE_FORMAT)]
@@ -27,6 +28,9 @@ pub enum SloCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// SLO ID
         #[arg(short, long)]
         id: String,
@@ -39,6 +43,9 @@ pub enum SloCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// SLO data (JSON file path or inline JSON)
         #[arg(long)]
         data: String,
@@ -51,6 +58,9 @@ pub enum SloCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// SLO ID
         #[arg(short, long)]
         id: String,
@@ -66,10 +76,34 @@ pub enum SloCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// SLO ID
         #[arg(short, long)]
         id: String,
     },
+    /// Report budget remaining and compliance for every SLO in one or more datasets
+    Report {
+        /// Dataset slugs (repeat the flag or separate with commas)
+        #[arg(short, long, required = true, value_delimiter = ',')]
+        dataset: Vec<String>,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
+        /// Start of the historical compliance window (Unix timestamp)
+        #[arg(long, requires = "end_time")]
+        start_time: Option<i64>,
+        /// End of the historical compliance window (Unix timestamp)
+        #[arg(long, requires = "start_time")]
+        end_time: Option<i64>,
+        /// Output format
+        #[arg(short, long, value_enum, default_value = DEFAULT_TABLE_FORMAT)]
+        format: OutputFormat,
+        /// Write the report as CSV (overrides --format)
+        #[arg(long)]
+        csv: bool,
+    },
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -80,10 +114,54 @@ pub struct Slo {
     pub sli: SloIndicator,
     pub target_percentage: f64,
     pub time_period: i32,
+    #[serde(default)]
+    pub budget_remaining: Option<f64>,
+    #[serde(default)]
+    pub compliance: Option<f64>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
 
+/// Detailed SLO fields used for reporting (`GET /1/slos/{dataset}/{id}?detailed=true`)
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SloDetail {
+    pub id: String,
+    pub name: String,
+    pub target_per_million: Option<f64>,
+    pub target_percentage: Option<f64>,
+    pub time_period_days: Option<i64>,
+    pub budget_remaining: Option<f64>,
+    pub compliance: Option<f64>,
+}
+
+impl SloDetail {
+    /// Target as a percentage, from either representation the API returns
+    pub fn target(&self) -> Option<f64> {
+        self.target_percentage
+            .or_else(|| self.target_per_million.map(|t| t / 10_000.0))
+    }
+}
+
+/// A point in an SLO's historical compliance series
+#[derive(Deserialize, Serialize, Debug)]
+pub struct HistoricalCompliance {
+    pub timestamp: Value,
+    pub compliance: f64,
+    pub budget_remaining: Option<f64>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SloReportRow {
+    pub dataset: String,
+    pub id: String,
+    pub name: String,
+    pub target: Option<f64>,
+    pub compliance: Option<f64>,
+    pub budget_remaining: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub historical: Option<Vec<HistoricalCompliance>>,
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct SloIndicator {
     pub query: SloQuery,
@@ -113,67 +191,62 @@ pub struct SloFilter {
 }
 
 impl SloCommands {
-    pub async fn execute(&self, client: &HoneycombClient, _context: &CommandContext) -> Result<()> {
+    fn environment(&self) -> Option<&str> {
+        match self {
+            SloCommands::List { environment, .. }
+            | SloCommands::Get { environment, .. }
+            | SloCommands::Create { environment, .. }
+            | SloCommands::Update { environment, .. }
+            | SloCommands::Delete { environment, .. }
+            | SloCommands::Report { environment, .. } => environment.as_deref(),
+        }
+    }
+
+    pub async fn execute(&self, client: &HoneycombClient, context: &CommandContext) -> Result<()> {
+        let client = &environment_client(client, context, self.environment()).await?;
+
         match self {
             SloCommands::List {
-                dataset,
-                environment,
-                format,
-            } => list_slos(client, dataset, environment.as_deref(), format).await,
+                dataset, format, ..
+            } => list_slos(client, dataset, format).await,
             SloCommands::Get {
                 dataset,
                 id,
                 format,
+                ..
             } => get_slo(client, dataset, id, format).await,
             SloCommands::Create {
                 dataset,
                 data,
                 format,
+                ..
             } => create_slo(client, dataset, data, format).await,
             SloCommands::Update {
                 dataset,
                 id,
                 data,
                 format,
+                ..
             } => update_slo(client, dataset, id, data, format).await,
-            SloCommands::Delete { dataset, id } => delete_slo(client, dataset, id).await,
+            SloCommands::Delete { dataset, id, .. } => delete_slo(client, dataset, id).await,
+            SloCommands::Report {
+                dataset,
+                start_time,
+                end_time,
+                format,
+                csv,
+                ..
+            } => {
+                let window = start_time.zip(*end_time);
+                report_slos(client, dataset, window, format, *csv).await
+            }
         }
     }
 }
 
-async fn list_slos(
-    client: &HoneycombClient,
-    dataset: &str,
-    environment: Option<&str>,
-    format: &OutputFormat,
-) -> Result<()> {
-    use crate::common::require_valid_environment;
-    use std::collections::HashMap;
-
-    // If environment is provided, validate it exists
-    if let Some(env) = environment {
-        let team = std::env::var("HONEYCOMB_TEAM").unwrap_or_else(|_| "default".to_string());
-        require_valid_environment(client, &team, env).await?;
-    }
-
+async fn list_slos(client: &HoneycombClient, dataset: &str, format: &OutputFormat) -> Result<()> {
     let path = format!("/1/slos/{}", dataset);
-
-    // Add environment as query parameter if provided
-    let mut query_params = HashMap::new();
-    if let Some(env) = environment {
-        query_params.insert("environment".to_string(), env.to_string());
-    }
-
-    let response = client
-        .get(
-            &path,
-            if query_params.is_empty() {
-                None
-            } else {
-                Some(&query_params)
-            },
-        )
-        .await?;
+    let response = client.get(&path, None).await?;
 
     match format {
         OutputFormat::Json => {
@@ -244,6 +317,9 @@ async fn create_slo(
 
     let path = format!("/1/slos/{}", dataset);
     let response = client.post(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -272,6 +348,9 @@ async fn update_slo(
 
     let path = format!("/1/slos/{}/{}", dataset, id);
     let response = client.put(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -288,8 +367,169 @@ async fn update_slo(
 async fn delete_slo(client: &HoneycombClient, dataset: &str, id: &str) -> Result<()> {
     let path = format!("/1/slos/{}/{}", dataset, id);
     client.delete(&path).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     println!("SLO '{}' in dataset '{}' deleted successfully", id, dataset);
 
     Ok(())
 }
+
+/// Build report rows for every SLO in the given datasets
+pub async fn build_slo_report(
+    client: &HoneycombClient,
+    datasets: &[String],
+    window: Option<(i64, i64)>,
+) -> Result<Vec<SloReportRow>> {
+    let mut detail_params = HashMap::new();
+    detail_params.insert("detailed".to_string(), "true".to_string());
+
+    let mut rows = Vec::new();
+    for dataset in datasets {
+        let path = format!("/1/slos/{}", dataset);
+        let response = client.get(&path, None).await?;
+
+        let ids: Vec<String> = response
+            .as_array()
+            .map(|slos| {
+                slos.iter()
+                    .filter_map(|slo| slo.get("id").and_then(|v| v.as_str()))
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        for id in ids {
+            let path = format!("/1/slos/{}/{}", dataset, id);
+            let detail: SloDetail =
+                serde_json::from_value(client.get(&path, Some(&detail_params)).await?)?;
+            rows.push(SloReportRow {
+                dataset: dataset.clone(),
+                target: detail.target(),
+                id: detail.id,
+                name: detail.name,
+                compliance: detail.compliance,
+                budget_remaining: detail.budget_remaining,
+                historical: None,
+            });
+        }
+    }
+
+    if let Some((start_time, end_time)) = window
+        && !rows.is_empty()
+    {
+        let ids: Vec<&str> = rows.iter().map(|r| r.id.as_str()).collect();
+        let body = json!({ "ids": ids, "start_time": start_time, "end_time": end_time });
+        let response = client.post("/1/reporting/slos/historical", &body).await?;
+        let mut history: HashMap<String, Vec<HistoricalCompliance>> =
+            serde_json::from_value(response)?;
+        for row in &mut rows {
+            row.historical = Some(history.remove(&row.id).unwrap_or_default());
+        }
+    }
+
+    Ok(rows)
+}
+
+async fn report_slos(
+    client: &HoneycombClient,
+    datasets: &[String],
+    window: Option<(i64, i64)>,
+    format: &OutputFormat,
+    csv: bool,
+) -> Result<()> {
+    let rows = build_slo_report(client, datasets, window).await?;
+    let historical = window.is_some();
+
+    if csv {
+        print_csv(&rows, historical);
+        return Ok(());
+    }
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string(&rows)?);
+        }
+        OutputFormat::Pretty => {
+            println!("{}", serde_json::to_string_pretty(&rows)?);
+        }
+        OutputFormat::Table => {
+            print!(
+                "{:<20} {:<15} {:<30} {:<10} {:<12} {:<12}",
+                "Dataset", "ID", "Name", "Target %", "Compliance", "Budget Left"
+            );
+            if historical {
+                print!(" Historical");
+            }
+            println!();
+            println!("{:-<105}", "");
+
+            for row in &rows {
+                print!(
+                    "{:<20} {:<15} {:<30} {:<10} {:<12} {:<12}",
+                    row.dataset,
+                    row.id,
+                    row.name,
+                    format_percent(row.target),
+                    format_percent(row.compliance),
+                    format_percent(row.budget_remaining)
+                );
+                if historical {
+                    print!(" {}", format_percent(last_compliance(row)));
+                }
+                println!();
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_csv(rows: &[SloReportRow], historical: bool) {
+    let mut header = "dataset,id,name,target,compliance,budget_remaining".to_string();
+    if historical {
+        header.push_str(",historical_compliance");
+    }
+    println!("{}", header);
+    for row in rows {
+        let mut fields = vec![
+            csv_field(&row.dataset),
+            csv_field(&row.id),
+            csv_field(&row.name),
+            format_number(row.target),
+            format_number(row.compliance),
+            format_number(row.budget_remaining),
+        ];
+        if historical {
+            fields.push(format_number(last_compliance(row)));
+        }
+        println!("{}", fields.join(","));
+    }
+}
+
+/// Compliance at the end of the historical window
+fn last_compliance(row: &SloReportRow) -> Option<f64> {
+    row.historical
+        .as_ref()
+        .and_then(|h| h.last())
+        .map(|h| h.compliance)
+}
+
+fn format_percent(value: Option<f64>) -> String {
+    value
+        .map(|v| format!("{:.2}%", v))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn format_number(value: Option<f64>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
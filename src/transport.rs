@@ -0,0 +1,94 @@
+use anyhow::Result;
+use reqwest::header::HeaderMap;
+use reqwest::{Client, Method, StatusCode, Url};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+
+/// Future returned by [`Transport::send`]
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = Result<HttpResponse>> + Send + 'a>>;
+
+/// A fully built request, including authentication headers
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    pub body: Option<Vec<u8>>,
+}
+
+/// A response with its body already read
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl HttpRequest {
+    /// Path and query string, without scheme and host
+    pub fn path_and_query(&self) -> String {
+        match self.url.query() {
+            Some(query) => format!("{}?{}", self.url.path(), query),
+            None => self.url.path().to_string(),
+        }
+    }
+}
+
+impl HttpResponse {
+    /// Body decoded as UTF-8 (invalid sequences are replaced)
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+/// Sends requests for [`HoneycombClient`](crate::client::HoneycombClient).
+///
+/// The default implementation uses reqwest; tests can substitute an in-memory
+/// fake or a [`ReplayTransport`](crate::cassette::ReplayTransport).
+pub trait Transport: Send + Sync + fmt::Debug {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_>;
+}
+
+/// Transport backed by a reqwest [`Client`]
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let mut builder = self
+                .client
+                .request(request.method, request.url)
+                .headers(request.headers);
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+
+            let response = builder.send().await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?.to_vec();
+
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+/// Whether a transport error is worth retrying (connection failures and timeouts)
+pub fn is_transient_error(err: &anyhow::Error) -> bool {
+    err.downcast_ref::<reqwest::Error>()
+        .is_some_and(|e| e.is_connect() || e.is_timeout())
+}
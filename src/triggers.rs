@@ -1,7 +1,7 @@
 use crate::client::HoneycombClient;
 use crate::common::{
-    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, pretty_print_json,
-    read_json_file,
+    CommandContext, DEFAULT_PRETTY_FORMAT, DEFAULT_TABLE_FORMAT, OutputFormat, environment_client,
+    pretty_print_json, read_json_file,
 };
 use anyhow::Result;
 use clap::Subcommand;
@@ -16,7 +16,7 @@ pub enum TriggerCommands {
         #[arg(short, long)]
         dataset: String,
         /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
-        #[arg(short, long, env = "HONEYCOMB_ENVIRONMENT")]
+        #[arg(short, long)]
         environment: Option<String>,
         /// Output format
         #[arg(short, long, default_value = DEFAULT_TABLE_FORMAT)]
@@ -27,6 +27,9 @@ pub enum TriggerCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Trigger ID
         #[arg(short, long)]
         id: String,
@@ -39,6 +42,9 @@ pub enum TriggerCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Trigger data (JSON file path or inline JSON)
         #[arg(long)]
         data: String,
@@ -51,6 +57,9 @@ pub enum TriggerCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Trigger ID
         #[arg(short, long)]
         id: String,
@@ -66,6 +75,9 @@ pub enum TriggerCommands {
         /// Dataset slug
         #[arg(short, long)]
         dataset: String,
+        /// Environment slug (optional, uses HONEYCOMB_ENVIRONMENT env var if not specified)
+        #[arg(short, long)]
+        environment: Option<String>,
         /// Trigger ID
         #[arg(short, long)]
         id: String,
@@ -117,30 +129,45 @@ pub struct TriggerThreshold {
 }
 
 impl TriggerCommands {
-    pub async fn execute(&self, client: &HoneycombClient, _context: &CommandContext) -> Result<()> {
+    fn environment(&self) -> Option<&str> {
+        match self {
+            TriggerCommands::List { environment, .. }
+            | TriggerCommands::Get { environment, .. }
+            | TriggerCommands::Create { environment, .. }
+            | TriggerCommands::Update { environment, .. }
+            | TriggerCommands::Delete { environment, .. } => environment.as_deref(),
+        }
+    }
+
+    pub async fn execute(&self, client: &HoneycombClient, context: &CommandContext) -> Result<()> {
+        let client = &environment_client(client, context, self.environment()).await?;
+
         match self {
             TriggerCommands::List {
-                dataset,
-                environment,
-                format,
-            } => list_triggers(client, dataset, environment.as_deref(), format).await,
+                dataset, format, ..
+            } => list_triggers(client, dataset, format).await,
             TriggerCommands::Get {
                 dataset,
                 id,
                 format,
+                ..
             } => get_trigger(client, dataset, id, format).await,
             TriggerCommands::Create {
                 dataset,
                 data,
                 format,
+                ..
             } => create_trigger(client, dataset, data, format).await,
             TriggerCommands::Update {
                 dataset,
                 id,
                 data,
                 format,
+                ..
             } => update_trigger(client, dataset, id, data, format).await,
-            TriggerCommands::Delete { dataset, id } => delete_trigger(client, dataset, id).await,
+            TriggerCommands::Delete { dataset, id, .. } => {
+                delete_trigger(client, dataset, id).await
+            }
         }
     }
 }
@@ -148,36 +175,10 @@ impl TriggerCommands {
 async fn list_triggers(
     client: &HoneycombClient,
     dataset: &str,
-    environment: Option<&str>,
     format: &OutputFormat,
 ) -> Result<()> {
-    use crate::common::require_valid_environment;
-    use std::collections::HashMap;
-
-    // If environment is provided, validate it exists
-    if let Some(env) = environment {
-        let team = std::env::var("HONEYCOMB_TEAM").unwrap_or_else(|_| "default".to_string());
-        require_valid_environment(client, &team, env).await?;
-    }
-
     let path = format!("/1/triggers/{}", dataset);
-
-    // Add environment as query parameter if provided
-    let mut query_params = HashMap::new();
-    if let Some(env) = environment {
-        query_params.insert("environment".to_string(), env.to_string());
-    }
-
-    let response = client
-        .get(
-            &path,
-            if query_params.is_empty() {
-                None
-            } else {
-                Some(&query_params)
-            },
-        )
-        .await?;
+    let response = client.get(&path, None).await?;
 
     match format {
         OutputFormat::Json => {
@@ -249,6 +250,9 @@ async fn create_trigger(
 
     let path = format!("/1/triggers/{}", dataset);
     let response = client.post(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -277,6 +281,9 @@ async fn update_trigger(
 
     let path = format!("/1/triggers/{}/{}", dataset, id);
     let response = client.put(&path, &json_data).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     match format {
         OutputFormat::Json => {
@@ -293,6 +300,9 @@ async fn update_trigger(
 async fn delete_trigger(client: &HoneycombClient, dataset: &str, id: &str) -> Result<()> {
     let path = format!("/1/triggers/{}/{}", dataset, id);
     client.delete(&path).await?;
+    if client.is_dry_run() {
+        return Ok(());
+    }
 
     println!(
         "Trigger '{}' in dataset '{}' deleted successfully",
@@ -13,7 +13,10 @@ use wiremock::{
 
 /// Helper function to create a test CommandContext
 fn create_test_context() -> CommandContext {
-    CommandContext { team: None }
+    CommandContext {
+        team: None,
+        environment: None,
+    }
 }
 
 /// Test Environments endpoints
@@ -21,6 +24,9 @@ mod environments {
     use super::*;
     use apiary::common::OutputFormat;
     use apiary::environments::EnvironmentCommands;
+    use apiary::pagination::{PageArgs, Pages};
+    use std::collections::HashMap;
+    use wiremock::matchers::query_param;
 
     #[tokio::test]
     async fn test_list_environments() {
@@ -45,20 +51,245 @@ mod environments {
             .await;
 
         let client =
-            HoneycombClient::new(Some("test-key".to_string()), None, Some(mock_server.uri()));
+            HoneycombClient::new(Some("test-key".to_string()), None, Some(mock_server.uri()))
+                .unwrap();
 
         let command = EnvironmentCommands::List {
             team: Some("test-team".to_string()),
+            page: PageArgs::default(),
             format: OutputFormat::Json,
         };
 
         let context = CommandContext {
             team: Some("test-team".to_string()),
+            environment: None,
         };
 
         let result = command.execute(&client, &context).await;
         assert!(result.is_ok());
     }
+    fn environment_page(ids: &[&str], next: Option<&str>) -> serde_json::Value {
+        let data: Vec<_> = ids
+            .iter()
+            .map(|id| {
+                json!({
+                    "id": id,
+                    "type": "environments",
+                    "attributes": { "name": id, "slug": id }
+                })
+            })
+            .collect();
+        json!({ "data": data, "links": { "next": next } })
+    }
+
+    async fn mount_environment_pages(mock_server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/2/teams/test-team/environments"))
+            .and(query_param("page[after]", "cursor-2"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(environment_page(&["env-3"], None)),
+            )
+            .mount(mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/2/teams/test-team/environments"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(environment_page(
+                &["env-1", "env-2"],
+                Some("/2/teams/test-team/environments?page%5Bafter%5D=cursor-2&page%5Bsize%5D=2"),
+            )))
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_pages_follow_next_links() {
+        let mock_server = MockServer::start().await;
+        mount_environment_pages(&mock_server).await;
+
+        let client =
+            HoneycombClient::new(Some("test-key".to_string()), None, Some(mock_server.uri()))
+                .unwrap();
+
+        let pages = Pages::new(
+            &client,
+            "/2/teams/test-team/environments",
+            HashMap::new(),
+            &PageArgs::all(),
+        );
+        let items = pages.collect().await.unwrap();
+        let ids: Vec<_> = items.iter().map(|i| i["id"].as_str().unwrap()).collect();
+        assert_eq!(ids, vec!["env-1", "env-2", "env-3"]);
+    }
+
+    #[tokio::test]
+    async fn test_pages_stop_at_limit() {
+        let mock_server = MockServer::start().await;
+        mount_environment_pages(&mock_server).await;
+
+        let client =
+            HoneycombClient::new(Some("test-key".to_string()), None, Some(mock_server.uri()))
+                .unwrap();
+
+        let args = PageArgs {
+            limit: Some(1),
+            all: false,
+        };
+        let mut pages = Pages::new(
+            &client,
+            "/2/teams/test-team/environments",
+            HashMap::new(),
+            &args,
+        );
+        let first = pages.next_page().await.unwrap().unwrap();
+        assert_eq!(first.len(), 1);
+        assert!(pages.next_page().await.unwrap().is_none());
+        assert!(pages.has_more());
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(
+            requests[0]
+                .url
+                .query()
+                .unwrap()
+                .contains("page%5Bsize%5D=1")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_first_page_only_by_default() {
+        let mock_server = MockServer::start().await;
+        mount_environment_pages(&mock_server).await;
+
+        let client =
+            HoneycombClient::new(Some("test-key".to_string()), None, Some(mock_server.uri()))
+                .unwrap();
+
+        let mut pages = Pages::new(
+            &client,
+            "/2/teams/test-team/environments",
+            HashMap::new(),
+            &PageArgs::default(),
+        );
+        assert_eq!(pages.next_page().await.unwrap().unwrap().len(), 2);
+        assert!(pages.next_page().await.unwrap().is_none());
+        assert!(pages.has_more());
+    }
+
+    #[tokio::test]
+    async fn test_list_environments_all_pages() {
+        let mock_server = MockServer::start().await;
+        mount_environment_pages(&mock_server).await;
+
+        let client =
+            HoneycombClient::new(Some("test-key".to_string()), None, Some(mock_server.uri()))
+                .unwrap();
+
+        let command = EnvironmentCommands::List {
+            team: Some("test-team".to_string()),
+            page: PageArgs::all(),
+            format: OutputFormat::Table,
+        };
+
+        let result = command.execute(&client, &create_test_context()).await;
+        assert!(result.is_ok());
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+    }
+}
+
+/// Test API Keys endpoints
+mod api_keys {
+    use super::*;
+    use apiary::api_keys::{ApiKeyCommands, ApiKeyType};
+    use apiary::common::OutputFormat;
+    use apiary::pagination::PageArgs;
+    use wiremock::matchers::query_param;
+
+    #[tokio::test]
+    async fn test_list_api_keys_with_filters() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/2/teams/test-team/environments"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(test_utils::sample_environment_data()),
+            )
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/2/teams/test-team/api-keys"))
+            .and(query_param("filter[type]", "ingest"))
+            .and(query_param("filter[environment_id]", "env-123"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(test_utils::sample_api_key_data()),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client =
+            HoneycombClient::new(Some("test-key".to_string()), None, Some(mock_server.uri()))
+                .unwrap();
+
+        let command = ApiKeyCommands::List {
+            team: Some("test-team".to_string()),
+            key_type: Some(ApiKeyType::Ingest),
+            environment: Some("production".to_string()),
+            page: PageArgs::default(),
+            format: OutputFormat::Table,
+        };
+
+        let result = command.execute(&client, &create_test_context()).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_list_api_keys_unknown_environment() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/2/teams/test-team/environments"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(test_utils::sample_environment_data()),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client =
+            HoneycombClient::new(Some("test-key".to_string()), None, Some(mock_server.uri()))
+                .unwrap();
+
+        let command = ApiKeyCommands::List {
+            team: Some("test-team".to_string()),
+            key_type: None,
+            environment: Some("staging".to_string()),
+            page: PageArgs::default(),
+            format: OutputFormat::Json,
+        };
+
+        let result = command.execute(&client, &create_test_context()).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_delete_api_key_requires_team() {
+        let client = HoneycombClient::new(
+            Some("test-key".to_string()),
+            None,
+            Some("https://api.test".to_string()),
+        )
+        .unwrap();
+
+        let command = ApiKeyCommands::Delete {
+            team: None,
+            id: "key-123".to_string(),
+        };
+
+        let result = command.execute(&client, &create_test_context()).await;
+        assert!(result.is_err());
+    }
 }
 
 /// Test Datasets endpoints  
@@ -86,7 +317,8 @@ mod datasets {
             None,
             Some("test-config-key".to_string()),
             Some(mock_server.uri()),
-        );
+        )
+        .unwrap();
 
         let response = client.get("/1/datasets", None).await;
         assert!(response.is_ok());
@@ -110,7 +342,8 @@ mod datasets {
             None,
             Some("test-config-key".to_string()),
             Some(mock_server.uri()),
-        );
+        )
+        .unwrap();
 
         let response = client.get("/1/datasets/test-dataset", None).await;
         assert!(response.is_ok());
@@ -145,7 +378,8 @@ mod columns {
             None,
             Some("test-config-key".to_string()),
             Some(mock_server.uri()),
-        );
+        )
+        .unwrap();
 
         let command = ColumnCommands::List {
             dataset: "test-dataset".to_string(),
@@ -187,7 +421,8 @@ mod triggers {
             None,
             Some("test-config-key".to_string()),
             Some(mock_server.uri()),
-        );
+        )
+        .unwrap();
 
         let command = TriggerCommands::List {
             dataset: "test-dataset".to_string(),
@@ -228,7 +463,8 @@ mod slos {
             None,
             Some("test-config-key".to_string()),
             Some(mock_server.uri()),
-        );
+        )
+        .unwrap();
 
         let command = SloCommands::List {
             dataset: "test-dataset".to_string(),
@@ -241,59 +477,95 @@ mod slos {
     }
 }
 
-/// Test Boards endpoints
-mod boards {
+/// Test SLO reporting
+mod slo_report {
     use super::*;
-    use apiary::boards::BoardCommands;
-    use apiary::common::OutputFormat;
+    use apiary::slos::{SloCommands, build_slo_report};
+    use wiremock::matchers::{body_json, query_param};
 
-    #[tokio::test]
-    async fn test_list_boards() {
-        let mock_server = MockServer::start().await;
+    async fn mount_slo_mocks(mock_server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/1/slos/test-dataset"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {"id": "slo-123", "name": "API Availability"}
+            ])))
+            .mount(mock_server)
+            .await;
 
-        // Mock environment validation endpoint
         Mock::given(method("GET"))
-            .and(path("/2/teams/default/environments"))
+            .and(path("/1/slos/test-dataset/slo-123"))
+            .and(query_param("detailed", "true"))
             .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-                "data": [
-                    {
-                        "id": "env-123",
-                        "type": "environment",
-                        "attributes": {
-                            "name": "Production",
-                            "slug": "production",
-                            "color": "blue"
-                        }
-                    }
-                ]
+                "id": "slo-123",
+                "name": "API Availability",
+                "target_per_million": 999000,
+                "time_period_days": 30,
+                "budget_remaining": 42.5,
+                "compliance": 99.95
             })))
-            .mount(&mock_server)
+            .mount(mock_server)
             .await;
+    }
 
-        // Mock boards list endpoint with environment query parameter
-        Mock::given(method("GET"))
-            .and(path("/1/boards"))
-            .and(wiremock::matchers::query_param("environment", "production"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
-                {
-                    "id": "board-123",
-                    "name": "Service Dashboard",
-                    "style": "visual",
-                    "created_at": "2023-01-01T00:00:00Z"
-                }
-            ])))
+    #[tokio::test]
+    async fn test_slo_report_reads_detailed_slos() {
+        let mock_server = MockServer::start().await;
+        mount_slo_mocks(&mock_server).await;
+
+        let client = HoneycombClient::new(
+            None,
+            Some("test-config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let rows = build_slo_report(&client, &["test-dataset".to_string()], None)
+            .await
+            .unwrap();
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].budget_remaining, Some(42.5));
+        assert_eq!(rows[0].compliance, Some(99.95));
+        assert_eq!(rows[0].target, Some(99.9));
+        assert!(rows[0].historical.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_slo_report_with_historical_compliance() {
+        let mock_server = MockServer::start().await;
+        mount_slo_mocks(&mock_server).await;
+
+        Mock::given(method("POST"))
+            .and(path("/1/reporting/slos/historical"))
+            .and(body_json(json!({
+                "ids": ["slo-123"],
+                "start_time": 1700000000,
+                "end_time": 1700086400
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "slo-123": [
+                    {"timestamp": 1700000000, "compliance": 99.9},
+                    {"timestamp": 1700086400, "compliance": 99.8}
+                ]
+            })))
+            .expect(1)
             .mount(&mock_server)
             .await;
 
         let client = HoneycombClient::new(
-            Some("test-key".to_string()),
+            None,
             Some("test-config-key".to_string()),
             Some(mock_server.uri()),
-        );
+        )
+        .unwrap();
 
-        let command = BoardCommands::List {
-            environment: Some("production".to_string()),
-            format: OutputFormat::Json,
+        let command = SloCommands::Report {
+            dataset: vec!["test-dataset".to_string()],
+            environment: None,
+            start_time: Some(1700000000),
+            end_time: Some(1700086400),
+            format: apiary::common::OutputFormat::Table,
+            csv: true,
         };
 
         let result = command.execute(&client, &create_test_context()).await;
@@ -301,27 +573,46 @@ mod boards {
     }
 }
 
-/// Test Markers endpoints
-mod markers {
+/// Test Service Map endpoints
+mod service_map {
     use super::*;
-    use apiary::common::OutputFormat;
-    use apiary::markers::MarkerCommands;
+    use apiary::service_map::{
+        GraphFormat, MapDependency, ServiceMapArgs, render_dot, render_mermaid,
+    };
+    use wiremock::matchers::body_json;
 
     #[tokio::test]
-    async fn test_list_markers() {
+    async fn test_service_map_polls_until_ready() {
         let mock_server = MockServer::start().await;
 
+        Mock::given(method("POST"))
+            .and(path("/1/maps/dependencies/requests"))
+            .and(body_json(json!({
+                "time_range": 3600,
+                "filters": [{"name": "frontend", "type": "service"}]
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "request_id": "map-123",
+                "status": "pending"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
         Mock::given(method("GET"))
-            .and(path("/1/markers/test-dataset"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
-                {
-                    "id": "marker-123",
-                    "message": "Deployment v1.2.3",
-                    "start_time": 1609459200,
-                    "color": "blue",
-                    "url": "https://github.com/repo/commit/abc123"
-                }
-            ])))
+            .and(path("/1/maps/dependencies/requests/map-123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "request_id": "map-123",
+                "status": "ready",
+                "dependencies": [
+                    {
+                        "parent_node": {"name": "frontend", "type": "service"},
+                        "child_node": {"name": "checkout", "type": "service"},
+                        "call_count": 42
+                    }
+                ]
+            })))
+            .expect(1)
             .mount(&mock_server)
             .await;
 
@@ -329,37 +620,113 @@ mod markers {
             None,
             Some("test-config-key".to_string()),
             Some(mock_server.uri()),
-        );
+        )
+        .unwrap();
 
-        let command = MarkerCommands::List {
-            dataset: "test-dataset".to_string(),
-            format: OutputFormat::Json,
+        let args = ServiceMapArgs {
             environment: None,
+            time_range: 3600,
+            start_time: None,
+            end_time: None,
+            service: vec!["frontend".to_string()],
+            limit: None,
+            timeout: 10,
+            poll_interval: 10,
+            format: apiary::common::OutputFormat::Table,
+            graph: Some(GraphFormat::Mermaid),
         };
 
-        let result = command.execute(&client, &create_test_context()).await;
+        let result = args.execute(&client, &create_test_context()).await;
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_render_graph_formats() {
+        let dependencies: Vec<MapDependency> = serde_json::from_value(json!([
+            {
+                "parent_node": {"name": "web-app", "type": "service"},
+                "child_node": {"name": "db \"primary\"", "type": "service"},
+                "call_count": 7
+            }
+        ]))
+        .unwrap();
+
+        let dot = render_dot(&dependencies);
+        assert!(dot.contains(r#""web-app" -> "db \"primary\"" [label="7"];"#));
+
+        let mermaid = render_mermaid(&dependencies);
+        assert_eq!(
+            mermaid,
+            "flowchart LR\n    n0[\"web-app\"]\n    n1[\"db #quot;primary#quot;\"]\n    n0 -->|7| n1\n"
+        );
+    }
+
+    #[test]
+    fn test_mermaid_keeps_similar_names_apart() {
+        let dependencies: Vec<MapDependency> = serde_json::from_value(json!([
+            {
+                "parent_node": {"name": "api-gw", "type": "service"},
+                "child_node": {"name": "api.gw", "type": "service"},
+                "call_count": 3
+            },
+            {
+                "parent_node": {"name": "api.gw", "type": "service"},
+                "child_node": {"name": "end", "type": "service"},
+                "call_count": 2
+            }
+        ]))
+        .unwrap();
+
+        let mermaid = render_mermaid(&dependencies);
+        assert!(mermaid.contains("    n0[\"api-gw\"]\n"));
+        assert!(mermaid.contains("    n1[\"api.gw\"]\n"));
+        // Mermaid keywords only ever appear as labels
+        assert!(mermaid.contains("    n2[\"end\"]\n"));
+        assert!(mermaid.contains("    n0 -->|3| n1\n"));
+        assert!(mermaid.contains("    n1 -->|2| n2\n"));
+    }
+
+    #[test]
+    fn test_service_map_end_time_requires_start_time() {
+        use clap::{Args, Command};
+
+        let command = ServiceMapArgs::augment_args(Command::new("service-map"));
+        let err = command
+            .try_get_matches_from(["service-map", "--end-time", "1700000000"])
+            .unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::MissingRequiredArgument);
+    }
 }
 
-/// Test Recipients endpoints
-mod recipients {
+/// Test Boards endpoints
+mod boards {
     use super::*;
+    use apiary::boards::BoardCommands;
     use apiary::common::OutputFormat;
-    use apiary::recipients::RecipientCommands;
 
     #[tokio::test]
-    async fn test_list_recipients() {
+    async fn test_list_boards() {
         let mock_server = MockServer::start().await;
 
+        // The configuration key must belong to the requested environment
         Mock::given(method("GET"))
-            .and(path("/1/recipients"))
+            .and(path("/1/auth"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "team": {"name": "Test Team", "slug": "test-team"},
+                "environment": {"name": "Production", "slug": "production"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        // Environment is selected by the key, not a query parameter
+        Mock::given(method("GET"))
+            .and(path("/1/boards"))
+            .and(wiremock::matchers::query_param_is_missing("environment"))
             .respond_with(ResponseTemplate::new(200).set_body_json(json!([
                 {
-                    "id": "recipient-123",
-                    "name": "Engineering Team",
-                    "type": "slack",
-                    "target": "#engineering",
+                    "id": "board-123",
+                    "name": "Service Dashboard",
+                    "style": "visual",
                     "created_at": "2023-01-01T00:00:00Z"
                 }
             ])))
@@ -367,12 +734,14 @@ mod recipients {
             .await;
 
         let client = HoneycombClient::new(
-            None,
+            Some("test-key".to_string()),
             Some("test-config-key".to_string()),
             Some(mock_server.uri()),
-        );
+        )
+        .unwrap();
 
-        let command = RecipientCommands::List {
+        let command = BoardCommands::List {
+            environment: Some("production".to_string()),
             format: OutputFormat::Json,
         };
 
@@ -381,28 +750,28 @@ mod recipients {
     }
 }
 
-/// Test Burn Alerts endpoints
-mod burn_alerts {
+/// Test Board Views endpoints
+mod board_views {
     use super::*;
-    use apiary::burn_alerts::BurnAlertCommands;
+    use apiary::boards::{BoardCommands, BoardViewCommands};
     use apiary::common::OutputFormat;
 
     #[tokio::test]
-    async fn test_list_burn_alerts() {
+    async fn test_list_board_views() {
         let mock_server = MockServer::start().await;
 
         Mock::given(method("GET"))
-            .and(path("/1/burn_alerts/test-dataset"))
+            .and(path("/1/boards/board-123/views"))
             .respond_with(ResponseTemplate::new(200).set_body_json(json!([
                 {
-                    "id": "alert-123",
-                    "slo_id": "slo-456",
-                    "exhaustion_minutes": 60,
-                    "alert_window_minutes": 5,
-                    "disabled": false,
-                    "recipients": []
+                    "id": "view-123",
+                    "name": "Checkout only",
+                    "filters": [
+                        {"column": "service.name", "operation": "=", "value": "checkout"}
+                    ]
                 }
             ])))
+            .expect(1)
             .mount(&mock_server)
             .await;
 
@@ -410,27 +779,607 @@ mod burn_alerts {
             None,
             Some("test-config-key".to_string()),
             Some(mock_server.uri()),
-        );
+        )
+        .unwrap();
 
-        let command = BurnAlertCommands::List {
-            dataset: "test-dataset".to_string(),
-            format: OutputFormat::Json,
+        let command = BoardCommands::Views {
             environment: None,
+            command: BoardViewCommands::List {
+                board: "board-123".to_string(),
+                format: OutputFormat::Table,
+            },
         };
 
         let result = command.execute(&client, &create_test_context()).await;
         assert!(result.is_ok());
     }
-}
-
-/// Test Calculated Fields endpoints
-mod calculated_fields {
-    use super::*;
-    use apiary::calculated_fields::CalculatedFieldCommands;
-    use apiary::common::OutputFormat;
 
     #[tokio::test]
-    async fn test_list_calculated_fields() {
+    async fn test_delete_board_view() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/1/boards/board-123/views/view-123"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::new(
+            None,
+            Some("test-config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let command = BoardCommands::Views {
+            environment: None,
+            command: BoardViewCommands::Delete {
+                board: "board-123".to_string(),
+                id: "view-123".to_string(),
+            },
+        };
+
+        let result = command.execute(&client, &create_test_context()).await;
+        assert!(result.is_ok());
+    }
+}
+
+/// Test Markers endpoints
+mod markers {
+    use super::*;
+    use apiary::common::OutputFormat;
+    use apiary::markers::MarkerCommands;
+
+    #[tokio::test]
+    async fn test_list_markers() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/markers/test-dataset"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {
+                    "id": "marker-123",
+                    "message": "Deployment v1.2.3",
+                    "start_time": 1609459200,
+                    "color": "blue",
+                    "url": "https://github.com/repo/commit/abc123"
+                }
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::new(
+            None,
+            Some("test-config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let command = MarkerCommands::List {
+            dataset: "test-dataset".to_string(),
+            format: OutputFormat::Json,
+            environment: None,
+        };
+
+        let result = command.execute(&client, &create_test_context()).await;
+        assert!(result.is_ok());
+    }
+}
+
+/// Test Queries endpoints
+mod queries {
+    use super::*;
+    use apiary::common::OutputFormat;
+    use apiary::queries::QueryCommands;
+    use wiremock::matchers::body_json;
+
+    #[tokio::test]
+    async fn test_create_query() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/1/queries/test-dataset"))
+            .and(body_json(json!({
+                "calculations": [{"op": "COUNT"}],
+                "time_range": 3600
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "query-123",
+                "calculations": [{"op": "COUNT"}],
+                "time_range": 3600
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::new(
+            None,
+            Some("test-config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let command = QueryCommands::Create {
+            environment: None,
+            dataset: "test-dataset".to_string(),
+            data: r#"{"calculations": [{"op": "COUNT"}], "time_range": 3600}"#.to_string(),
+            format: OutputFormat::Json,
+        };
+
+        let result = command.execute(&client, &create_test_context()).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_query_rejects_invalid_spec() {
+        let client = HoneycombClient::new(
+            None,
+            Some("test-config-key".to_string()),
+            Some("https://api.test".to_string()),
+        )
+        .unwrap();
+
+        let command = QueryCommands::Create {
+            environment: None,
+            dataset: "test-dataset".to_string(),
+            data: r#"{"calculations": "COUNT"}"#.to_string(),
+            format: OutputFormat::Json,
+        };
+
+        let result = command.execute(&client, &create_test_context()).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_query() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/queries/test-dataset/query-123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "query-123",
+                "calculations": [{"op": "COUNT"}]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::new(
+            None,
+            Some("test-config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let command = QueryCommands::Get {
+            environment: None,
+            dataset: "test-dataset".to_string(),
+            id: "query-123".to_string(),
+            format: OutputFormat::Json,
+        };
+
+        let result = command.execute(&client, &create_test_context()).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_run_query_polls_until_complete() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/1/queries/test-dataset"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "query-123"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/1/query_results/test-dataset"))
+            .and(body_json(json!({
+                "query_id": "query-123",
+                "disable_series": false
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "id": "result-456",
+                "complete": false
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/query_results/test-dataset/result-456"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "result-456",
+                "complete": false
+            })))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/query_results/test-dataset/result-456"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "result-456",
+                "complete": true,
+                "data": {
+                    "series": [
+                        {"time": "2023-01-01T00:00:00Z", "data": {"COUNT": 12, "service": "api"}}
+                    ],
+                    "results": [
+                        {"data": {"COUNT": 12, "service": "api"}}
+                    ]
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::new(
+            None,
+            Some("test-config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let command = QueryCommands::Run {
+            environment: None,
+            dataset: "test-dataset".to_string(),
+            data: r#"{"breakdowns": ["service"], "calculations": [{"op": "COUNT"}]}"#.to_string(),
+            timeout: 10,
+            poll_interval: 10,
+            format: OutputFormat::Table,
+        };
+
+        let result = command.execute(&client, &create_test_context()).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_run_query_times_out() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/1/queries/test-dataset"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "query-123"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/1/query_results/test-dataset"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "id": "result-456",
+                "complete": false
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::new(
+            None,
+            Some("test-config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let command = QueryCommands::Run {
+            environment: None,
+            dataset: "test-dataset".to_string(),
+            data: r#"{"calculations": [{"op": "COUNT"}]}"#.to_string(),
+            timeout: 0,
+            poll_interval: 10,
+            format: OutputFormat::Json,
+        };
+
+        let result = command.execute(&client, &create_test_context()).await;
+        assert!(result.unwrap_err().to_string().contains("Timed out"));
+    }
+}
+
+/// Test Query Annotations endpoints
+mod query_annotations {
+    use super::*;
+    use apiary::common::OutputFormat;
+    use apiary::query_annotations::QueryAnnotationCommands;
+
+    #[tokio::test]
+    async fn test_list_query_annotations() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/query_annotations/test-dataset"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {
+                    "id": "annotation-123",
+                    "name": "Slow requests",
+                    "description": "P99 latency by endpoint",
+                    "query_id": "query-123",
+                    "created_at": "2023-01-01T00:00:00Z",
+                    "updated_at": "2023-01-01T00:00:00Z"
+                }
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::new(
+            None,
+            Some("test-config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let command = QueryAnnotationCommands::List {
+            dataset: "test-dataset".to_string(),
+            format: OutputFormat::Table,
+            environment: None,
+        };
+
+        let result = command.execute(&client, &create_test_context()).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_update_query_annotation() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("PUT"))
+            .and(path("/1/query_annotations/test-dataset/annotation-123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "annotation-123",
+                "name": "Renamed",
+                "query_id": "query-123"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::new(
+            None,
+            Some("test-config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let command = QueryAnnotationCommands::Update {
+            environment: None,
+            dataset: "test-dataset".to_string(),
+            id: "annotation-123".to_string(),
+            data: r#"{"name": "Renamed", "query_id": "query-123"}"#.to_string(),
+            format: OutputFormat::Json,
+        };
+
+        let result = command.execute(&client, &create_test_context()).await;
+        assert!(result.is_ok());
+    }
+}
+
+/// Test Recipients endpoints
+mod recipients {
+    use super::*;
+    use apiary::common::OutputFormat;
+    use apiary::recipients::RecipientCommands;
+
+    #[tokio::test]
+    async fn test_list_recipients() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/recipients"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {
+                    "id": "recipient-123",
+                    "name": "Engineering Team",
+                    "type": "slack",
+                    "target": "#engineering",
+                    "created_at": "2023-01-01T00:00:00Z"
+                }
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::new(
+            None,
+            Some("test-config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let command = RecipientCommands::List {
+            format: OutputFormat::Json,
+        };
+
+        let result = command.execute(&client, &create_test_context()).await;
+        assert!(result.is_ok());
+    }
+}
+
+/// Test Burn Alerts endpoints
+mod burn_alerts {
+    use super::*;
+    use apiary::burn_alerts::BurnAlertCommands;
+    use apiary::common::OutputFormat;
+
+    #[tokio::test]
+    async fn test_list_burn_alerts() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/burn_alerts/test-dataset"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {
+                    "id": "alert-123",
+                    "slo_id": "slo-456",
+                    "exhaustion_minutes": 60,
+                    "alert_window_minutes": 5,
+                    "disabled": false,
+                    "recipients": []
+                }
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::new(
+            None,
+            Some("test-config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let command = BurnAlertCommands::List {
+            dataset: "test-dataset".to_string(),
+            format: OutputFormat::Json,
+            environment: None,
+        };
+
+        let result = command.execute(&client, &create_test_context()).await;
+        assert!(result.is_ok());
+    }
+}
+
+/// Test Events endpoints
+mod events {
+    use super::*;
+    use apiary::common::OutputFormat;
+    use apiary::events::EventCommands;
+    use wiremock::matchers::{body_json, header};
+
+    #[tokio::test]
+    async fn test_send_event_with_metadata_headers() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/1/events/test-dataset"))
+            .and(header("X-Honeycomb-Team", "test-config-key"))
+            .and(header("X-Honeycomb-Event-Time", "2023-01-01T00:00:00Z"))
+            .and(header("X-Honeycomb-Samplerate", "10"))
+            .and(body_json(json!({"service": "api", "duration_ms": 12})))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::new(
+            None,
+            Some("test-config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let command = EventCommands::Send {
+            environment: None,
+            dataset: "test-dataset".to_string(),
+            data: r#"{"service": "api", "duration_ms": 12}"#.to_string(),
+            timestamp: Some("2023-01-01T00:00:00Z".to_string()),
+            sample_rate: Some(10),
+            format: OutputFormat::Json,
+        };
+
+        let result = command.execute(&client, &create_test_context()).await;
+        assert!(result.is_ok());
+    }
+
+    /// Matches requests whose gzipped body decodes to the expected JSON
+    struct GzipJsonBody(serde_json::Value);
+
+    impl wiremock::Match for GzipJsonBody {
+        fn matches(&self, request: &wiremock::Request) -> bool {
+            use std::io::Read;
+            let mut decoded = String::new();
+            flate2::read::GzDecoder::new(&request.body[..])
+                .read_to_string(&mut decoded)
+                .is_ok()
+                && serde_json::from_str::<serde_json::Value>(&decoded).ok() == Some(self.0.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_reports_failed_lines() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/1/batch/test-dataset"))
+            .and(header("Content-Encoding", "gzip"))
+            .and(GzipJsonBody(json!([
+                {"data": {"service": "api"}},
+                {"data": {"service": "web"}, "time": "2023-01-01T00:00:00Z", "samplerate": 5}
+            ])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {"status": 202},
+                {"status": 400, "error": "event too large"}
+            ])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/1/batch/test-dataset"))
+            .and(GzipJsonBody(json!([{"data": {"service": "db"}}])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                {"status": 202}
+            ])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::new(
+            None,
+            Some("test-config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let input = concat!(
+            "{\"service\": \"api\"}\n",
+            "{\"data\": {\"service\": \"web\"}, \"time\": \"2023-01-01T00:00:00Z\", \"samplerate\": 5}\n",
+            "\n",
+            "not json\n",
+            "{\"service\": \"db\"}\n",
+        );
+
+        let summary =
+            apiary::events::send_batch_from_reader(&client, "test-dataset", input.as_bytes(), 2)
+                .await
+                .unwrap();
+
+        assert_eq!(summary.sent, 3);
+        assert_eq!(summary.accepted, 2);
+        let failed_lines: Vec<usize> = summary.failed.iter().map(|f| f.line).collect();
+        assert_eq!(failed_lines, vec![2, 4]);
+        assert_eq!(summary.failed[0].status, Some(400));
+    }
+
+    #[tokio::test]
+    async fn test_send_event_rejects_non_object() {
+        let client = HoneycombClient::new(
+            None,
+            Some("test-config-key".to_string()),
+            Some("https://api.test".to_string()),
+        )
+        .unwrap();
+
+        let command = EventCommands::Send {
+            environment: None,
+            dataset: "test-dataset".to_string(),
+            data: "[1, 2, 3]".to_string(),
+            timestamp: None,
+            sample_rate: None,
+            format: OutputFormat::Json,
+        };
+
+        let result = command.execute(&client, &create_test_context()).await;
+        assert!(result.is_err());
+    }
+}
+
+/// Test Calculated Fields endpoints
+mod calculated_fields {
+    use super::*;
+    use apiary::calculated_fields::CalculatedFieldCommands;
+    use apiary::common::OutputFormat;
+
+    #[tokio::test]
+    async fn test_list_calculated_fields() {
         let mock_server = MockServer::start().await;
 
         Mock::given(method("GET"))
@@ -450,7 +1399,8 @@ mod calculated_fields {
             None,
             Some("test-config-key".to_string()),
             Some(mock_server.uri()),
-        );
+        )
+        .unwrap();
 
         let command = CalculatedFieldCommands::List {
             dataset: "test-dataset".to_string(),
@@ -488,7 +1438,8 @@ mod dataset_definitions {
             None,
             Some("test-config-key".to_string()),
             Some(mock_server.uri()),
-        );
+        )
+        .unwrap();
 
         let command = DatasetDefinitionCommands::Get {
             dataset: "test-dataset".to_string(),
@@ -529,7 +1480,8 @@ mod marker_settings {
             None,
             Some("test-config-key".to_string()),
             Some(mock_server.uri()),
-        );
+        )
+        .unwrap();
 
         let command = MarkerSettingCommands::List {
             dataset: "test-dataset".to_string(),
@@ -570,11 +1522,444 @@ mod auth {
             None,
             Some("test-config-key".to_string()),
             Some(mock_server.uri()),
-        );
+        )
+        .unwrap();
 
         let command = AuthCommands::Info;
 
         let result = command.execute(&client, &create_test_context()).await;
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_validate_config_key_uses_v1_auth() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/auth"))
+            .and(wiremock::matchers::header(
+                "X-Honeycomb-Team",
+                "test-config-key",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "key-123",
+                "type": "configuration",
+                "api_key_access": {"events": true, "markers": true, "triggers": false},
+                "environment": {"name": "Production", "slug": "production"},
+                "team": {"name": "Test Team", "slug": "test-team"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::new(
+            None,
+            Some("test-config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let command = AuthCommands::Validate {
+            format: apiary::common::OutputFormat::Table,
+        };
+
+        let result = command.execute(&client, &create_test_context()).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_validate_checks_both_keys() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/2/auth"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": {
+                    "id": "hcxmk_123",
+                    "type": "api-keys",
+                    "attributes": {
+                        "name": "CI",
+                        "key_type": "management",
+                        "scopes": ["environments:read"],
+                        "disabled": false,
+                        "timestamps": {"created": "2023-01-01", "updated": "2023-01-01"}
+                    },
+                    "relationships": {"team": {"data": {"type": "teams", "id": "team-1"}}}
+                },
+                "included": []
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/auth"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+                "error": "unknown API key"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::new(
+            Some("test-mgmt-key".to_string()),
+            Some("test-config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let command = AuthCommands::Validate {
+            format: apiary::common::OutputFormat::Json,
+        };
+
+        let err = command
+            .execute(&client, &create_test_context())
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Configuration key validation failed")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_checks_config_key_after_management_failure() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/2/auth"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+                "error": "unknown API key"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/auth"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "environment": {"name": "Production", "slug": "production"},
+                "team": {"name": "Test Team", "slug": "test-team"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::new(
+            Some("test-mgmt-key".to_string()),
+            Some("test-config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let command = AuthCommands::Validate {
+            format: apiary::common::OutputFormat::Table,
+        };
+
+        let err = command
+            .execute(&client, &create_test_context())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Management key validation failed"));
+        assert!(matches!(
+            err.downcast_ref::<apiary::errors::ApiaryError>(),
+            Some(apiary::errors::ApiaryError::ApiError { status: 401, .. })
+        ));
+    }
+}
+
+/// Test raw API passthrough
+mod api_passthrough {
+    use super::*;
+    use apiary::api::ApiArgs;
+    use apiary::common::OutputFormat;
+    use apiary::errors::ApiaryError;
+    use wiremock::matchers::{body_json, header, query_param};
+
+    fn api_args(method: &str, path: &str) -> ApiArgs {
+        ApiArgs {
+            method: method.to_string(),
+            path: path.to_string(),
+            data: None,
+            params: vec![],
+            headers: vec![],
+            format: OutputFormat::Json,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_v2_path_uses_management_key() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/2/teams/test-team/environments"))
+            .and(header("Authorization", "Bearer mgmt-key"))
+            .and(query_param("page[size]", "5"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"data": []})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::new(
+            Some("mgmt-key".to_string()),
+            Some("config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let mut args = api_args("get", "/2/teams/test-team/environments");
+        args.params = vec!["page[size]=5".to_string()];
+
+        let result = args.execute(&client, &create_test_context()).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_v1_post_with_data_and_headers() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/1/markers/test-dataset"))
+            .and(header("X-Honeycomb-Team", "config-key"))
+            .and(header("X-Request-Source", "apiary"))
+            .and(body_json(json!({"message": "deploy"})))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({"id": "marker-1"})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::new(
+            Some("mgmt-key".to_string()),
+            Some("config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let mut args = api_args("POST", "/1/markers/test-dataset");
+        args.data = Some(r#"{"message": "deploy"}"#.to_string());
+        args.headers = vec!["X-Request-Source=apiary".to_string()];
+
+        let result = args.execute(&client, &create_test_context()).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_error_status_is_typed() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/1/boards/missing"))
+            .respond_with(
+                ResponseTemplate::new(404).set_body_json(json!({"error": "Board not found"})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::new(
+            None,
+            Some("config-key".to_string()),
+            Some(mock_server.uri()),
+        )
+        .unwrap();
+
+        let err = api_args("DELETE", "/1/boards/missing")
+            .execute(&client, &create_test_context())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ApiaryError>(),
+            Some(ApiaryError::NotFound { .. } | ApiaryError::ApiError { status: 404, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_malformed_param_is_validation_error() {
+        let client = HoneycombClient::new(
+            None,
+            Some("config-key".to_string()),
+            Some("https://api.honeycomb.io".to_string()),
+        )
+        .unwrap();
+
+        let mut args = api_args("GET", "/1/datasets");
+        args.params = vec!["no-equals-sign".to_string()];
+
+        let err = args
+            .execute(&client, &create_test_context())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ApiaryError>(),
+            Some(ApiaryError::ValidationError(_))
+        ));
+    }
+}
+
+/// Test dry-run rendering of mutating requests
+mod dry_run {
+    use super::*;
+    use apiary::dry_run::{DryRunFormat, render};
+    use apiary::transport::HttpRequest;
+
+    fn dry_run_client(uri: String, format: DryRunFormat) -> HoneycombClient {
+        HoneycombClient::builder()
+            .management_key(Some("hcxmk_id:mgmt-secret".to_string()))
+            .config_key(Some("config-secret".to_string()))
+            .base_url(Some(uri))
+            .dry_run(Some(format))
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_mutating_request_is_rendered_not_sent() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("PUT"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let client = dry_run_client(mock_server.uri(), DryRunFormat::Http);
+
+        let response = client
+            .put(
+                "/1/triggers/test-dataset/trigger-123",
+                &json!({"name": "High error rate"}),
+            )
+            .await
+            .unwrap();
+        // The placeholder response echoes the body so commands can carry on
+        assert_eq!(response, json!({"name": "High error rate"}));
+        assert_eq!(client.dry_run_count(), 1);
+    }
+
+    fn request(method: reqwest::Method, url: &str, header: (&'static str, &str)) -> HttpRequest {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(header.0, header.1.parse().unwrap());
+        HttpRequest {
+            method,
+            url: url.parse().unwrap(),
+            headers,
+            body: None,
+        }
+    }
+
+    #[test]
+    fn test_http_format_redacts_config_key() {
+        let mut request = request(
+            reqwest::Method::PUT,
+            "https://api.honeycomb.io/1/triggers/test-dataset/trigger-123",
+            ("x-honeycomb-team", "config-secret"),
+        );
+        request.body = Some(br#"{"name":"High error rate"}"#.to_vec());
+
+        let preview = render(DryRunFormat::Http, &request);
+        assert!(preview.starts_with("PUT /1/triggers/test-dataset/trigger-123 HTTP/1.1"));
+        assert!(preview.contains("x-honeycomb-team: <redacted>"));
+        assert!(preview.contains("\"name\": \"High error rate\""));
+        assert!(!preview.contains("config-secret"));
+    }
+
+    #[test]
+    fn test_curl_format_redacts_bearer_token() {
+        let request = request(
+            reqwest::Method::DELETE,
+            "https://api.honeycomb.io/2/teams/test-team/environments/env-123",
+            ("authorization", "Bearer hcxmk_id:mgmt-secret"),
+        );
+
+        let preview = render(DryRunFormat::Curl, &request);
+        assert!(preview.starts_with("curl -X DELETE"));
+        assert!(preview.contains(
+            "-H \"authorization: Bearer $HONEYCOMB_MANAGEMENT_API_KEY_ID:$HONEYCOMB_MANAGEMENT_API_KEY\""
+        ));
+        assert!(!preview.contains("mgmt-secret"));
+    }
+
+    #[tokio::test]
+    async fn test_reads_are_still_sent() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/datasets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = dry_run_client(mock_server.uri(), DryRunFormat::Http);
+
+        let response = client.get("/1/datasets", None).await;
+        assert!(response.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_every_batch_chunk_is_rendered() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let client = dry_run_client(mock_server.uri(), DryRunFormat::Http);
+        let input = "{\"a\": 1}\n{\"a\": 2}\n{\"a\": 3}\n";
+
+        let summary =
+            apiary::events::send_batch_from_reader(&client, "test-dataset", input.as_bytes(), 2)
+                .await
+                .unwrap();
+
+        assert_eq!(summary.sent, 0);
+        assert!(summary.failed.is_empty());
+        assert_eq!(client.dry_run_count(), 2);
+    }
+
+    #[test]
+    fn test_query_creation_is_held_back() {
+        // Creating a query saves it on Honeycomb; running one does not
+        let post = reqwest::Method::POST;
+        assert!(apiary::dry_run::is_mutating(
+            &post,
+            "/1/queries/test-dataset"
+        ));
+        assert!(!apiary::dry_run::is_mutating(
+            &post,
+            "/1/query_results/test-dataset"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_read_only_posts_are_sent() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/1/query_results/test-dataset"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "result-1",
+                "complete": true,
+                "data": {"results": []}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = dry_run_client(mock_server.uri(), DryRunFormat::Http);
+
+        let result = apiary::queries::fetch_query_result(
+            &client,
+            "test-dataset",
+            "query-1",
+            std::time::Duration::from_secs(5),
+            std::time::Duration::from_millis(10),
+        )
+        .await
+        .unwrap();
+
+        assert!(result.complete);
+        assert_eq!(client.dry_run_count(), 0);
+    }
 }
@@ -0,0 +1,297 @@
+//! Tests for the on-disk GET response cache
+
+use apiary::cache::ResponseCache;
+use apiary::client::HoneycombClient;
+use assert_cmd::Command;
+use predicates::prelude::*;
+use serde_json::json;
+use std::path::Path;
+use std::time::Duration;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+fn cached_client(uri: String, config_key: &str, dir: &Path, ttl: Duration) -> HoneycombClient {
+    HoneycombClient::builder()
+        .config_key(Some(config_key.to_string()))
+        .base_url(Some(uri))
+        .cache(Some(
+            ResponseCache::new(dir, ttl).scope(Some("my-team".to_string()), None),
+        ))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_repeated_get_is_served_from_cache() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/1/datasets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{"slug": "web"}])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let client = cached_client(
+        mock_server.uri(),
+        "hcaik_key_one",
+        dir.path(),
+        Duration::from_secs(60),
+    );
+
+    for _ in 0..3 {
+        let datasets = client.get("/1/datasets", None).await.unwrap();
+        assert_eq!(datasets, json!([{"slug": "web"}]));
+    }
+
+    // The cache survives across clients (i.e. separate invocations)
+    let client = cached_client(
+        mock_server.uri(),
+        "hcaik_key_one",
+        dir.path(),
+        Duration::from_secs(60),
+    );
+    client.get("/1/datasets", None).await.unwrap();
+
+    // Keys never end up on disk
+    for entry in std::fs::read_dir(dir.path()).unwrap() {
+        let content = std::fs::read_to_string(entry.unwrap().path()).unwrap();
+        assert!(!content.contains("hcaik_key_one"));
+    }
+}
+
+#[tokio::test]
+async fn test_cache_is_keyed_by_api_key_and_query() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/1/columns/web"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(3)
+        .mount(&mock_server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let ttl = Duration::from_secs(60);
+    let first = cached_client(mock_server.uri(), "hcaik_key_one", dir.path(), ttl);
+    let second = cached_client(mock_server.uri(), "hcaik_key_two", dir.path(), ttl);
+
+    first.get("/1/columns/web", None).await.unwrap();
+    second.get("/1/columns/web", None).await.unwrap();
+
+    let mut params = std::collections::HashMap::new();
+    params.insert("key_name".to_string(), "duration_ms".to_string());
+    first.get("/1/columns/web", Some(&params)).await.unwrap();
+
+    // All three are now cached
+    first.get("/1/columns/web", None).await.unwrap();
+    second.get("/1/columns/web", None).await.unwrap();
+    first.get("/1/columns/web", Some(&params)).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_expired_entries_are_refetched() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/1/datasets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let client = cached_client(mock_server.uri(), "hcaik_key", dir.path(), Duration::ZERO);
+
+    client.get("/1/datasets", None).await.unwrap();
+    client.get("/1/datasets", None).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_writes_invalidate_and_errors_are_not_cached() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/1/datasets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/1/datasets"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({"slug": "new"})))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/1/datasets/missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({"error": "not found"})))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let client = cached_client(
+        mock_server.uri(),
+        "hcaik_key",
+        dir.path(),
+        Duration::from_secs(60),
+    );
+
+    client.get("/1/datasets", None).await.unwrap();
+    client
+        .post("/1/datasets", &json!({"name": "new"}))
+        .await
+        .unwrap();
+    client.get("/1/datasets", None).await.unwrap();
+
+    assert!(client.get("/1/datasets/missing", None).await.is_err());
+    assert!(client.get("/1/datasets/missing", None).await.is_err());
+}
+
+#[tokio::test]
+async fn test_query_result_polls_are_not_cached() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/1/query_results/web"))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(json!({"id": "r1", "complete": false})),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/1/query_results/web/r1"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({"id": "r1", "complete": false})),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/1/query_results/web/r1"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({"id": "r1", "complete": true})),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let client = cached_client(
+        mock_server.uri(),
+        "hcaik_key",
+        dir.path(),
+        Duration::from_secs(60),
+    );
+
+    let result = apiary::queries::fetch_query_result(
+        &client,
+        "web",
+        "q1",
+        Duration::from_secs(5),
+        Duration::from_millis(10),
+    )
+    .await
+    .unwrap();
+    assert!(result.complete);
+    assert_eq!(std::fs::read_dir(dir.path()).map_or(0, |d| d.count()), 0);
+}
+
+#[tokio::test]
+async fn test_sending_events_keeps_the_cache() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/1/datasets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/1/events/web"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let client = cached_client(
+        mock_server.uri(),
+        "hcaik_key",
+        dir.path(),
+        Duration::from_secs(60),
+    );
+
+    client.get("/1/datasets", None).await.unwrap();
+    client
+        .post("/1/events/web", &json!({"duration_ms": 12}))
+        .await
+        .unwrap();
+    client.get("/1/datasets", None).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_writes_with_no_cache_still_invalidate() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/1/datasets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/1/datasets"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({"slug": "new"})))
+        .mount(&mock_server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let ttl = Duration::from_secs(60);
+    let client = cached_client(mock_server.uri(), "hcaik_key", dir.path(), ttl);
+    let no_cache = HoneycombClient::builder()
+        .config_key(Some("hcaik_key".to_string()))
+        .base_url(Some(mock_server.uri()))
+        .cache(Some(ResponseCache::new(dir.path(), ttl).bypass(true)))
+        .build()
+        .unwrap();
+
+    client.get("/1/datasets", None).await.unwrap();
+    no_cache
+        .post("/1/datasets", &json!({"name": "new"}))
+        .await
+        .unwrap();
+    // The write cleared the entry, so this goes to the API again
+    client.get("/1/datasets", None).await.unwrap();
+}
+
+#[test]
+fn test_clear_removes_entries() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("0123456789abcdef.json"), "{}").unwrap();
+    std::fs::write(dir.path().join("fedcba9876543210.json"), "{}").unwrap();
+    // Left behind by a write interrupted before its rename
+    std::fs::write(dir.path().join("0123456789abcdef.tmp4242"), "{").unwrap();
+
+    assert_eq!(ResponseCache::clear(dir.path()).unwrap(), 2);
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    assert_eq!(ResponseCache::clear(dir.path()).unwrap(), 0);
+    assert_eq!(
+        ResponseCache::clear(&dir.path().join("does-not-exist")).unwrap(),
+        0
+    );
+}
+
+#[test]
+fn test_cache_clear_command() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("0123456789abcdef.json"), "{}").unwrap();
+
+    let mut cmd = Command::new("cargo");
+    cmd.args(["run", "--"]);
+    cmd.env_remove("HONEYCOMB_CONFIGURATION_API_KEY");
+
+    cmd.args(["--cache-dir"])
+        .arg(dir.path())
+        .args(["cache", "clear"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Removed 1 cached responses"));
+}
@@ -0,0 +1,267 @@
+//! Tests for named profiles in the config file
+
+use apiary::config::{ConfigFile, Profile};
+use apiary::errors::ApiaryError;
+use assert_cmd::Command;
+use clap::{Arg, Command as ClapCommand};
+use predicates::prelude::*;
+use std::path::Path;
+
+fn apiary(config_file: &Path) -> Command {
+    let mut cmd = Command::new("cargo");
+    cmd.args(["run", "--"]);
+    cmd.env("APIARY_CONFIG_FILE", config_file);
+    cmd.env_remove("APIARY_PROFILE");
+    cmd
+}
+
+fn eu_profile() -> Profile {
+    Profile {
+        api_endpoint: Some("api.eu1.honeycomb.io".to_string()),
+        team: Some("eu-team".to_string()),
+        environment: Some("prod".to_string()),
+        config_key_env: Some("EU_PROD_CONFIG_KEY".to_string()),
+        ..Profile::default()
+    }
+}
+
+#[test]
+fn test_config_file_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nested").join("config.toml");
+
+    // A missing file is an empty config
+    assert_eq!(ConfigFile::load(&path).unwrap(), ConfigFile::default());
+
+    let mut file = ConfigFile {
+        current_profile: Some("eu".to_string()),
+        ..ConfigFile::default()
+    };
+    file.profiles.insert("eu".to_string(), eu_profile());
+    file.save(&path).unwrap();
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(content.contains("[profiles.eu]"));
+    assert!(content.contains("api_endpoint = \"api.eu1.honeycomb.io\""));
+    assert_eq!(ConfigFile::load(&path).unwrap(), file);
+}
+
+#[test]
+fn test_invalid_config_file_is_config_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    std::fs::write(&path, "[profiles.eu]\napi_key = \"hcaik_literal\"\n").unwrap();
+
+    let err = ConfigFile::load(&path).unwrap_err();
+    assert!(matches!(err, ApiaryError::ConfigError(_)), "{:?}", err);
+}
+
+#[test]
+fn test_active_profile_selection() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    let mut file = ConfigFile::default();
+    file.profiles.insert("eu".to_string(), eu_profile());
+    file.profiles.insert("us".to_string(), Profile::default());
+
+    assert!(file.active_profile(None, &path).unwrap().is_none());
+
+    file.current_profile = Some("us".to_string());
+    assert_eq!(file.active_name(None), Some("us"));
+    assert_eq!(
+        file.active_profile(Some("eu"), &path).unwrap(),
+        Some(&eu_profile())
+    );
+    assert!(matches!(
+        file.active_profile(Some("missing"), &path),
+        Err(ApiaryError::ConfigError(_))
+    ));
+}
+
+#[test]
+fn test_profile_get_and_set_validate_keys() {
+    let mut profile = Profile::default();
+    profile.set("team", Some("my-team".to_string())).unwrap();
+    assert_eq!(profile.get("team").unwrap(), Some("my-team"));
+    profile.set("team", None).unwrap();
+    assert_eq!(profile.get("team").unwrap(), None);
+
+    assert!(matches!(
+        profile.set("api_key", Some("hcaik_x".to_string())),
+        Err(ApiaryError::ValidationError(_))
+    ));
+    assert!(matches!(
+        profile.set("config_key_env", Some("$(rm -rf ~)".to_string())),
+        Err(ApiaryError::ValidationError(_))
+    ));
+
+    profile
+        .set("environment_keys.prod", Some("PROD_CONFIG_KEY".to_string()))
+        .unwrap();
+    assert_eq!(
+        profile.get("environment_keys.prod").unwrap(),
+        Some("PROD_CONFIG_KEY")
+    );
+    assert!(matches!(
+        profile.set("environment_keys.prod", Some("not a name".to_string())),
+        Err(ApiaryError::ValidationError(_))
+    ));
+    profile.set("environment_keys.prod", None).unwrap();
+    assert!(profile.environment_keys.is_empty());
+}
+
+#[test]
+fn test_shell_exports_reference_keys_without_values() {
+    let exports = eu_profile().shell_exports();
+    assert!(exports.contains("export HONEYCOMB_API_ENDPOINT='api.eu1.honeycomb.io'\n"));
+    assert!(exports.contains("export HONEYCOMB_TEAM='eu-team'\n"));
+    assert!(exports.contains("unset HONEYCOMB_API_URL\n"));
+    assert!(exports.contains("export HONEYCOMB_CONFIGURATION_API_KEY=\"${EU_PROD_CONFIG_KEY}\"\n"));
+    assert!(!exports.contains("export HONEYCOMB_MANAGEMENT_API_KEY"));
+
+    let mut profile = eu_profile();
+    profile
+        .environment_keys
+        .insert("prod".to_string(), "EU_PROD_KEY".to_string());
+    assert!(
+        profile
+            .shell_exports()
+            .contains("export HONEYCOMB_CONFIGURATION_API_KEY_PROD=\"${EU_PROD_KEY}\"\n")
+    );
+}
+
+#[test]
+fn test_apply_overrides_environment_fallbacks() {
+    let command = ClapCommand::new("test")
+        .arg(Arg::new("team").long("team").env("HONEYCOMB_TEAM"))
+        .arg(
+            Arg::new("config_key")
+                .long("config-key")
+                .env("HONEYCOMB_CONFIGURATION_API_KEY"),
+        )
+        .subcommand(
+            ClapCommand::new("list")
+                .arg(
+                    Arg::new("environment")
+                        .long("environment")
+                        .env("HONEYCOMB_ENVIRONMENT"),
+                )
+                // Unrelated options named like a profile setting are left alone
+                .arg(Arg::new("team").long("team")),
+        );
+    let command = eu_profile().apply(command);
+
+    let matches = command
+        .clone()
+        .try_get_matches_from(["test", "list"])
+        .unwrap();
+    assert_eq!(matches.get_one::<String>("team").unwrap(), "eu-team");
+    let list = matches.subcommand_matches("list").unwrap();
+    assert_eq!(list.get_one::<String>("environment").unwrap(), "prod");
+    assert!(list.get_one::<String>("team").is_none());
+
+    // Flags still win over the profile
+    let matches = command
+        .clone()
+        .try_get_matches_from(["test", "--team", "other", "list"])
+        .unwrap();
+    assert_eq!(matches.get_one::<String>("team").unwrap(), "other");
+
+    // Key options read from the variable the profile names
+    let key_arg = command
+        .get_arguments()
+        .find(|arg| arg.get_id() == "config_key")
+        .unwrap();
+    assert_eq!(
+        key_arg.get_env().and_then(|env| env.to_str()),
+        Some("EU_PROD_CONFIG_KEY")
+    );
+}
+
+#[test]
+fn test_config_commands() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+
+    apiary(&path)
+        .args(["config", "current"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No profile selected"));
+
+    apiary(&path)
+        .args(["--profile", "eu", "config", "set", "team", "eu-team"])
+        .assert()
+        .success();
+    apiary(&path)
+        .args(["--profile", "eu", "config", "set", "environment", "prod"])
+        .assert()
+        .success();
+    apiary(&path)
+        .args(["config", "use", "eu"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Switched to profile 'eu'"));
+
+    apiary(&path)
+        .args(["config", "list"])
+        .assert()
+        .success()
+        .stdout("* eu\n");
+    apiary(&path)
+        .args(["config", "get", "team"])
+        .assert()
+        .success()
+        .stdout("eu-team\n");
+    apiary(&path)
+        .args(["config", "env"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "export HONEYCOMB_ENVIRONMENT='prod'",
+        ));
+
+    apiary(&path)
+        .args(["config", "use", "missing"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Profile 'missing' not found"));
+}
+
+#[test]
+fn test_profile_supplies_endpoint_and_key_reference() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    let mut profile = eu_profile();
+    // Keeps the profile's environment from being checked against the live API
+    profile
+        .environment_keys
+        .insert("prod".to_string(), "EU_PROD_CONFIG_KEY".to_string());
+    let mut file = ConfigFile::default();
+    file.profiles.insert("eu".to_string(), profile);
+    file.save(&path).unwrap();
+
+    apiary(&path)
+        .env("APIARY_PROFILE", "eu")
+        .env("HONEYCOMB_API_ENDPOINT", "api.honeycomb.io")
+        .env("EU_PROD_CONFIG_KEY", "hcaik_eu_key")
+        .env_remove("HONEYCOMB_CONFIGURATION_API_KEY")
+        .args([
+            "--dry-run",
+            "markers",
+            "delete",
+            "--dataset",
+            "web",
+            "--id",
+            "m1",
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Host: api.eu1.honeycomb.io"));
+
+    apiary(&path)
+        .args(["--profile", "missing", "auth", "validate"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Profile 'missing' not found"));
+}
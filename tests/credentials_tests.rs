@@ -0,0 +1,128 @@
+//! Tests for API keys printed by credential helper commands
+
+use apiary::config::Profile;
+use apiary::credentials::key_from_command;
+use apiary::errors::ApiaryError;
+use assert_cmd::Command;
+use serde_json::json;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{header, method, path},
+};
+
+#[test]
+fn test_key_is_trimmed_output() {
+    assert_eq!(
+        key_from_command("printf '  hcaik_from_helper\\n'").unwrap(),
+        "hcaik_from_helper"
+    );
+}
+
+#[test]
+fn test_helper_runs_once_per_process() {
+    let dir = tempfile::tempdir().unwrap();
+    let log = dir.path().join("runs");
+    let command = format!("echo run >> '{}'; echo hcaik_cached", log.display());
+
+    assert_eq!(key_from_command(&command).unwrap(), "hcaik_cached");
+    assert_eq!(key_from_command(&command).unwrap(), "hcaik_cached");
+    assert_eq!(std::fs::read_to_string(&log).unwrap(), "run\n");
+}
+
+#[test]
+fn test_failing_or_silent_helpers_are_config_errors() {
+    let err = key_from_command("printf 'hcaik_%s' partial; exit 3").unwrap_err();
+    assert!(matches!(err, ApiaryError::ConfigError(_)), "{:?}", err);
+    assert!(!err.to_string().contains("hcaik_partial"));
+
+    let err = key_from_command("true").unwrap_err();
+    assert!(err.to_string().contains("printed nothing"), "{}", err);
+}
+
+#[test]
+fn test_profile_exports_key_commands() {
+    let mut profile = Profile::default();
+    profile
+        .set(
+            "config_key_command",
+            Some("pass show honeycomb/config".to_string()),
+        )
+        .unwrap();
+
+    assert_eq!(
+        profile.get("config_key_command").unwrap(),
+        Some("pass show honeycomb/config")
+    );
+    assert!(
+        profile.shell_exports().contains(
+            "export HONEYCOMB_CONFIGURATION_API_KEY_COMMAND='pass show honeycomb/config'\n"
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_cli_uses_keys_from_helpers() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/1/datasets/web"))
+        .and(header("X-Honeycomb-Team", "hcaik_from_helper"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"slug": "web"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/2/teams/my-team/environments"))
+        .and(header("Authorization", "Bearer hcxmk_id:helper_secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"data": []})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let run = |args: &[&str]| {
+        let mut cmd = Command::new("cargo");
+        cmd.args(["run", "--"]);
+        cmd.env("HONEYCOMB_CONFIGURATION_API_KEY", "hcaik_stale_key")
+            .env_remove("HONEYCOMB_MANAGEMENT_API_KEY_ID")
+            .env_remove("HONEYCOMB_MANAGEMENT_API_KEY")
+            .env_remove("HONEYCOMB_ENVIRONMENT")
+            .env("APIARY_CONFIG_FILE", "/nonexistent/apiary/config.toml")
+            .args(["--api-url", &mock_server.uri()])
+            .args([
+                "--config-key-command",
+                "echo hcaik_from_helper",
+                "--management-key-command",
+                "echo hcxmk_id:helper_secret",
+            ])
+            .args(args)
+            .assert()
+    };
+
+    run(&["datasets", "get", "--dataset", "web"]).success();
+    run(&["environments", "list", "--team", "my-team"]).success();
+}
+
+#[tokio::test]
+async fn test_key_flag_beats_helper_from_environment() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/1/datasets/web"))
+        .and(header("X-Honeycomb-Team", "hcaik_from_flag"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"slug": "web"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let mut cmd = Command::new("cargo");
+    cmd.args(["run", "--"]);
+    cmd.env(
+        "HONEYCOMB_CONFIGURATION_API_KEY_COMMAND",
+        "echo hcaik_from_helper",
+    )
+    .env_remove("HONEYCOMB_ENVIRONMENT")
+    .env("APIARY_CONFIG_FILE", "/nonexistent/apiary/config.toml")
+    .args(["--api-url", &mock_server.uri()])
+    .args(["--config-key", "hcaik_from_flag"])
+    .args(["datasets", "get", "--dataset", "web"])
+    .assert()
+    .success();
+}
@@ -1,11 +1,29 @@
-use apiary::client::HoneycombClient;
-use apiary::common::{require_valid_environment, validate_environment};
+use apiary::client::{HoneycombClient, environment_key_var};
+use apiary::columns::ColumnCommands;
+use apiary::common::{
+    CommandContext, OutputFormat, environment_client, require_valid_environment,
+    validate_environment,
+};
+use apiary::errors::ApiaryError;
+use assert_cmd::Command;
 use serde_json::json;
+use std::collections::BTreeMap;
 use wiremock::{
     Mock, MockServer, ResponseTemplate,
-    matchers::{method, path},
+    matchers::{header, method, path},
 };
 
+async fn mount_auth(mock_server: &MockServer, environment: &str) {
+    Mock::given(method("GET"))
+        .and(path("/1/auth"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "team": {"name": "Test Team", "slug": "test-team"},
+            "environment": {"name": environment, "slug": environment}
+        })))
+        .mount(mock_server)
+        .await;
+}
+
 #[tokio::test]
 async fn test_validate_environment_with_valid_slug() {
     let mock_server = MockServer::start().await;
@@ -36,7 +54,8 @@ async fn test_validate_environment_with_valid_slug() {
         Some("test-mgmt-key".to_string()),
         Some("test-config-key".to_string()),
         Some(mock_server.uri()),
-    );
+    )
+    .unwrap();
 
     let result = validate_environment(&client, "test-team", "production")
         .await
@@ -73,7 +92,8 @@ async fn test_validate_environment_with_valid_name() {
         Some("test-mgmt-key".to_string()),
         Some("test-config-key".to_string()),
         Some(mock_server.uri()),
-    );
+    )
+    .unwrap();
 
     let result = validate_environment(&client, "test-team", "Production")
         .await
@@ -110,7 +130,8 @@ async fn test_validate_environment_with_invalid_environment() {
         Some("test-mgmt-key".to_string()),
         Some("test-config-key".to_string()),
         Some(mock_server.uri()),
-    );
+    )
+    .unwrap();
 
     let result = validate_environment(&client, "test-team", "nonexistent")
         .await
@@ -147,7 +168,8 @@ async fn test_require_valid_environment_success() {
         Some("test-mgmt-key".to_string()),
         Some("test-config-key".to_string()),
         Some(mock_server.uri()),
-    );
+    )
+    .unwrap();
 
     let result = require_valid_environment(&client, "test-team", "production").await;
     assert!(result.is_ok(), "Should succeed for valid environment");
@@ -168,7 +190,8 @@ async fn test_require_valid_environment_failure() {
         Some("test-mgmt-key".to_string()),
         Some("test-config-key".to_string()),
         Some(mock_server.uri()),
-    );
+    )
+    .unwrap();
 
     let result = require_valid_environment(&client, "test-team", "invalid-env").await;
     assert!(result.is_err(), "Should fail for invalid environment");
@@ -188,7 +211,8 @@ async fn test_require_valid_environment_without_management_key() {
         None,
         Some("test-config-key".to_string()),
         Some("http://127.0.0.1:9".to_string()),
-    );
+    )
+    .unwrap();
 
     let result = require_valid_environment(&client, "test-team", "production").await;
     assert!(
@@ -196,3 +220,201 @@ async fn test_require_valid_environment_without_management_key() {
         "Environment validation should be skipped when no management key is present"
     );
 }
+
+#[tokio::test]
+async fn test_environment_uses_mapped_configuration_key() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/1/auth"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/1/columns/web/col-1"))
+        .and(header("X-Honeycomb-Team", "prod-config-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": "col-1"})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = HoneycombClient::builder()
+        .config_key(Some("dev-config-key".to_string()))
+        .environment_keys(BTreeMap::from([(
+            "prod".to_string(),
+            "prod-config-key".to_string(),
+        )]))
+        .base_url(Some(mock_server.uri()))
+        .build()
+        .unwrap();
+
+    let command = ColumnCommands::Get {
+        dataset: "web".to_string(),
+        environment: Some("prod".to_string()),
+        id: "col-1".to_string(),
+        format: OutputFormat::Json,
+    };
+    command
+        .execute(
+            &client,
+            &CommandContext {
+                team: None,
+                environment: None,
+            },
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_environment_accepts_key_for_that_environment() {
+    let mock_server = MockServer::start().await;
+    mount_auth(&mock_server, "dev").await;
+    Mock::given(method("GET"))
+        .and(path("/1/datasets"))
+        .and(header("X-Honeycomb-Team", "dev-config-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = HoneycombClient::new(
+        None,
+        Some("dev-config-key".to_string()),
+        Some(mock_server.uri()),
+    )
+    .unwrap();
+    let context = CommandContext {
+        team: None,
+        environment: None,
+    };
+
+    let scoped = environment_client(&client, &context, Some("dev"))
+        .await
+        .unwrap();
+    scoped.get("/1/datasets", None).await.unwrap();
+
+    // No environment means no check at all
+    environment_client(&client, &context, None).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_environment_rejects_key_for_another_environment() {
+    let mock_server = MockServer::start().await;
+    mount_auth(&mock_server, "dev").await;
+
+    let client = HoneycombClient::new(
+        None,
+        Some("dev-config-key".to_string()),
+        Some(mock_server.uri()),
+    )
+    .unwrap();
+
+    let err = environment_client(
+        &client,
+        &CommandContext {
+            team: None,
+            environment: None,
+        },
+        Some("prod"),
+    )
+    .await
+    .unwrap_err();
+    let message = err.to_string();
+    assert!(
+        matches!(
+            err.downcast_ref::<ApiaryError>(),
+            Some(ApiaryError::ConfigError(_))
+        ),
+        "{:?}",
+        err
+    );
+    assert!(message.contains("belongs to environment 'dev', not 'prod'"));
+    assert!(message.contains("HONEYCOMB_CONFIGURATION_API_KEY_PROD"));
+}
+
+#[test]
+fn test_environment_key_variable_names() {
+    assert_eq!(
+        environment_key_var("prod"),
+        "HONEYCOMB_CONFIGURATION_API_KEY_PROD"
+    );
+    assert_eq!(
+        environment_key_var("us-east.staging"),
+        "HONEYCOMB_CONFIGURATION_API_KEY_US_EAST_STAGING"
+    );
+}
+
+#[test]
+fn test_context_resolves_flag_before_global_setting() {
+    let context = CommandContext {
+        team: Some("global-team".to_string()),
+        environment: Some("dev".to_string()),
+    };
+    assert_eq!(context.team(Some("flag-team")).unwrap(), "flag-team");
+    assert_eq!(context.team(None).unwrap(), "global-team");
+    assert_eq!(context.environment(Some("prod")), Some("prod"));
+    assert_eq!(context.environment(None), Some("dev"));
+
+    let context = CommandContext {
+        team: None,
+        environment: None,
+    };
+    let err = context.team(None).unwrap_err();
+    assert!(err.to_string().contains("Team is required"), "{}", err);
+    assert_eq!(context.environment(None), None);
+}
+
+#[tokio::test]
+async fn test_global_team_and_environment_flags() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/2/teams/global-team/environments"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"data": []})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/1/columns/web"))
+        .and(header("X-Honeycomb-Team", "prod-config-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let apiary = |args: &[&str]| {
+        let mut cmd = Command::new("cargo");
+        cmd.args(["run", "--"]);
+        cmd.env("HONEYCOMB_TEAM", "env-team")
+            .env("HONEYCOMB_ENVIRONMENT", "dev")
+            .env("HONEYCOMB_MANAGEMENT_API_KEY_ID", "hcxmk_test")
+            .env("HONEYCOMB_MANAGEMENT_API_KEY", "secret")
+            .env("HONEYCOMB_CONFIGURATION_API_KEY", "dev-config-key")
+            .env("HONEYCOMB_CONFIGURATION_API_KEY_PROD", "prod-config-key")
+            .env("APIARY_CONFIG_FILE", "/nonexistent/apiary/config.toml")
+            .args(["--api-url", &mock_server.uri()])
+            .args(args)
+            .assert()
+            .success();
+    };
+
+    // The global flags beat the environment variables for every subcommand
+    apiary(&["--team", "global-team", "environments", "list"]);
+    apiary(&[
+        "--environment",
+        "prod",
+        "columns",
+        "list",
+        "--dataset",
+        "web",
+    ]);
+    // --environment is global, so it is accepted after the subcommand as well
+    apiary(&[
+        "columns",
+        "list",
+        "--dataset",
+        "web",
+        "--environment",
+        "prod",
+    ]);
+}
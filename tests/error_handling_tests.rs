@@ -2,7 +2,9 @@
 //! Tests authentication, rate limiting, and various error conditions
 
 use apiary::client::HoneycombClient;
+use apiary::retry::RetryConfig;
 use serde_json::json;
+use std::time::Duration;
 use wiremock::{
     Mock, MockServer, ResponseTemplate,
     matchers::{method, path, query_param},
@@ -29,7 +31,8 @@ mod authentication {
             Some("invalid-key".to_string()),
             None,
             Some(mock_server.uri()),
-        );
+        )
+        .unwrap();
 
         let response = client.get("/2/teams/test-team/environments", None).await;
         assert!(response.is_err());
@@ -51,7 +54,8 @@ mod authentication {
             None,
             Some("invalid-key".to_string()),
             Some(mock_server.uri()),
-        );
+        )
+        .unwrap();
 
         let response = client.get("/1/datasets", None).await;
         assert!(response.is_err());
@@ -59,7 +63,7 @@ mod authentication {
 
     #[tokio::test]
     async fn test_missing_required_key() {
-        let client = HoneycombClient::new(None, None, Some("http://api.test".to_string()));
+        let client = HoneycombClient::new(None, None, Some("http://api.test".to_string())).unwrap();
 
         // Should fail without any API keys
         let response = client.get("/1/datasets", None).await;
@@ -85,13 +89,424 @@ mod rate_limiting {
             .await;
 
         let client =
-            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()));
+            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()))
+                .unwrap();
+
+        let response = client.get("/1/datasets", None).await;
+        assert!(response.is_err());
+    }
+}
+
+/// Test automatic retries for transient failures
+mod retries {
+    use super::*;
+
+    fn fast_retry_config() -> RetryConfig {
+        RetryConfig {
+            base_delay: Duration::from_millis(1),
+            ..RetryConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_honors_retry_after() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/datasets"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("Retry-After", "0")
+                    .set_body_json(json!({"error": "Rate limit exceeded"})),
+            )
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/datasets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client =
+            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()))
+                .unwrap();
+
+        let response = client.get("/1/datasets", None).await;
+        assert!(response.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_service_unavailable_recovers() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/1/triggers/test-dataset/trigger-123"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/1/triggers/test-dataset/trigger-123"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::builder()
+            .config_key(Some("test-key".to_string()))
+            .base_url(Some(mock_server.uri()))
+            .retry_config(fast_retry_config())
+            .build()
+            .unwrap();
+
+        let response = client.delete("/1/triggers/test-dataset/trigger-123").await;
+        assert!(response.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_post_is_not_retried_by_default() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/1/markers/test-dataset"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::builder()
+            .config_key(Some("test-key".to_string()))
+            .base_url(Some(mock_server.uri()))
+            .retry_config(fast_retry_config())
+            .build()
+            .unwrap();
+
+        let response = client
+            .post("/1/markers/test-dataset", &json!({"message": "deploy"}))
+            .await;
+        assert!(response.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_max_retries_limits_attempts() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/datasets"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::builder()
+            .config_key(Some("test-key".to_string()))
+            .base_url(Some(mock_server.uri()))
+            .retry_config(RetryConfig {
+                max_retries: 2,
+                ..fast_retry_config()
+            })
+            .build()
+            .unwrap();
+
+        let response = client.get("/1/datasets", None).await;
+        assert!(response.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_beyond_timeout_is_not_awaited() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/datasets"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "120"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::builder()
+            .config_key(Some("test-key".to_string()))
+            .base_url(Some(mock_server.uri()))
+            .retry_config(RetryConfig {
+                retry_timeout: Duration::from_secs(5),
+                ..fast_retry_config()
+            })
+            .build()
+            .unwrap();
+
+        let response = client.get("/1/datasets", None).await;
+        assert!(response.is_err());
+    }
+}
+
+/// Test client construction errors and transport settings
+mod client_builder {
+    use super::*;
+    use apiary::client::default_user_agent;
+    use apiary::errors::ApiaryError;
+    use wiremock::matchers::header;
+
+    fn config_error(result: Result<HoneycombClient, ApiaryError>) -> String {
+        match result {
+            Err(ApiaryError::ConfigError(message)) => message,
+            Err(other) => panic!("expected a ConfigError, got {:?}", other),
+            Ok(_) => panic!("expected client construction to fail"),
+        }
+    }
+
+    #[test]
+    fn test_unsupported_scheme_is_config_error() {
+        let result = HoneycombClient::builder()
+            .base_url(Some("ftp://api.honeycomb.io".to_string()))
+            .build();
+        assert!(config_error(result).contains("ftp://api.honeycomb.io"));
+    }
+
+    #[test]
+    fn test_new_returns_config_error_instead_of_panicking() {
+        let result = HoneycombClient::new(None, None, Some("ftp://api.honeycomb.io".to_string()));
+        assert!(config_error(result).contains("ftp://api.honeycomb.io"));
+    }
+
+    #[test]
+    fn test_invalid_proxy_is_config_error() {
+        let result = HoneycombClient::builder()
+            .proxy(Some("not a proxy url".to_string()))
+            .build();
+        assert!(config_error(result).contains("Invalid proxy URL"));
+    }
+
+    #[test]
+    fn test_missing_ca_bundle_is_config_error() {
+        let result = HoneycombClient::builder()
+            .ca_bundle(Some("/nonexistent/ca.pem".into()))
+            .build();
+        assert!(config_error(result).contains("/nonexistent/ca.pem"));
+    }
+
+    #[test]
+    fn test_client_cert_requires_key() {
+        let result = HoneycombClient::builder()
+            .client_identity(Some("/nonexistent/client.pem".into()), None)
+            .build();
+        assert!(config_error(result).contains("provided together"));
+    }
+
+    #[tokio::test]
+    async fn test_default_user_agent() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/datasets"))
+            .and(header("user-agent", default_user_agent().as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client =
+            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()))
+                .unwrap();
+
+        let response = client.get("/1/datasets", None).await;
+        assert!(response.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_custom_user_agent_and_timeouts() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/datasets"))
+            .and(header("user-agent", "corp-tooling/1.0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::builder()
+            .config_key(Some("test-key".to_string()))
+            .base_url(Some(mock_server.uri()))
+            .user_agent(Some("corp-tooling/1.0".to_string()))
+            .timeout(Some(Duration::from_secs(5)))
+            .connect_timeout(Some(Duration::from_secs(2)))
+            .build()
+            .unwrap();
+
+        let response = client.get("/1/datasets", None).await;
+        assert!(response.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout_is_enforced() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/datasets"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!([]))
+                    .set_delay(Duration::from_secs(2)),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::builder()
+            .config_key(Some("test-key".to_string()))
+            .base_url(Some(mock_server.uri()))
+            .timeout(Some(Duration::from_millis(100)))
+            .retry_config(RetryConfig {
+                max_retries: 0,
+                ..RetryConfig::default()
+            })
+            .build()
+            .unwrap();
 
         let response = client.get("/1/datasets", None).await;
         assert!(response.is_err());
     }
 }
 
+/// Test typed errors and their exit codes
+mod typed_errors {
+    use super::*;
+    use apiary::errors::{ApiaryError, exit_code, exit_codes, parse_api_error};
+
+    fn api_error(err: &anyhow::Error) -> &ApiaryError {
+        err.downcast_ref::<ApiaryError>()
+            .expect("expected an ApiaryError")
+    }
+
+    #[tokio::test]
+    async fn test_not_found_is_typed() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/1/triggers/test-dataset/missing"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+                "error": "Trigger not found"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client =
+            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()))
+                .unwrap();
+
+        let err = client
+            .get("/1/triggers/test-dataset/missing", None)
+            .await
+            .unwrap_err();
+
+        match api_error(&err) {
+            ApiaryError::ApiError {
+                status, message, ..
+            } => {
+                assert_eq!(*status, 404);
+                assert_eq!(message, "Trigger not found");
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert_eq!(exit_code(&err), exit_codes::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_exit_code() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/1/boards/board-123"))
+            .respond_with(ResponseTemplate::new(429).set_body_string("slow down"))
+            .mount(&mock_server)
+            .await;
+
+        let client = HoneycombClient::builder()
+            .config_key(Some("test-key".to_string()))
+            .base_url(Some(mock_server.uri()))
+            .retry_config(RetryConfig {
+                max_retries: 0,
+                ..RetryConfig::default()
+            })
+            .build()
+            .unwrap();
+
+        let err = client.delete("/1/boards/board-123").await.unwrap_err();
+        assert!(err.to_string().contains("slow down"));
+        assert_eq!(exit_code(&err), exit_codes::RATE_LIMITED);
+    }
+
+    #[tokio::test]
+    async fn test_missing_key_exit_code() {
+        let client =
+            HoneycombClient::new(None, None, Some("https://api.test".to_string())).unwrap();
+
+        let err = client.get("/2/auth", None).await.unwrap_err();
+        assert!(matches!(
+            api_error(&err),
+            ApiaryError::AuthenticationRequired(_)
+        ));
+        assert_eq!(exit_code(&err), exit_codes::AUTH);
+    }
+
+    #[tokio::test]
+    async fn test_auth_commands_without_keys_exit_code() {
+        use apiary::auth::AuthCommands;
+        use apiary::common::{CommandContext, OutputFormat};
+
+        let client =
+            HoneycombClient::new(None, None, Some("https://api.test".to_string())).unwrap();
+        let context = CommandContext {
+            team: None,
+            environment: None,
+        };
+
+        for command in [
+            AuthCommands::Validate {
+                format: OutputFormat::Json,
+            },
+            AuthCommands::Region {
+                format: OutputFormat::Json,
+            },
+        ] {
+            let err = command.execute(&client, &context).await.unwrap_err();
+            assert!(err.to_string().contains("No API keys configured"));
+            assert_eq!(exit_code(&err), exit_codes::AUTH);
+        }
+    }
+
+    #[test]
+    fn test_parse_json_api_error_body() {
+        let body = json!({
+            "errors": [{"status": "422", "title": "Invalid", "detail": "name is required"}]
+        });
+
+        let err = parse_api_error(422, &body, "https://api.test/2/teams/t/api-keys");
+        assert_eq!(err.exit_code(), exit_codes::VALIDATION);
+        assert!(err.to_string().contains("name is required"));
+    }
+
+    #[test]
+    fn test_server_and_auth_status_mapping() {
+        assert_eq!(
+            parse_api_error(503, &json!({}), "u").exit_code(),
+            exit_codes::SERVER_ERROR
+        );
+        assert_eq!(
+            parse_api_error(401, &json!({"error": "bad key"}), "u").exit_code(),
+            exit_codes::AUTH
+        );
+        assert_eq!(
+            parse_api_error(403, &json!({}), "u").exit_code(),
+            exit_codes::AUTH
+        );
+    }
+}
+
 /// Test resource not found scenarios
 mod not_found {
     use super::*;
@@ -109,7 +524,8 @@ mod not_found {
             .await;
 
         let client =
-            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()));
+            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()))
+                .unwrap();
 
         let response = client.get("/1/datasets/nonexistent", None).await;
         assert!(response.is_err());
@@ -128,7 +544,8 @@ mod not_found {
             .await;
 
         let client =
-            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()));
+            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()))
+                .unwrap();
 
         let response = client
             .get("/1/triggers/test-dataset/nonexistent-trigger", None)
@@ -149,7 +566,8 @@ mod not_found {
             .await;
 
         let client =
-            HoneycombClient::new(Some("test-key".to_string()), None, Some(mock_server.uri()));
+            HoneycombClient::new(Some("test-key".to_string()), None, Some(mock_server.uri()))
+                .unwrap();
 
         let response = client
             .get("/2/teams/test-team/api-keys/nonexistent-key", None)
@@ -175,7 +593,8 @@ mod validation_errors {
             .await;
 
         let client =
-            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()));
+            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()))
+                .unwrap();
 
         let invalid_data = json!({
             "name": "", // Empty name should be invalid
@@ -200,7 +619,8 @@ mod validation_errors {
             .await;
 
         let client =
-            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()));
+            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()))
+                .unwrap();
 
         let incomplete_marker = json!({
             "color": "blue"
@@ -231,7 +651,8 @@ mod server_errors {
             .await;
 
         let client =
-            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()));
+            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()))
+                .unwrap();
 
         let response = client.get("/1/datasets", None).await;
         assert!(response.is_err());
@@ -250,7 +671,8 @@ mod server_errors {
             .await;
 
         let client =
-            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()));
+            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()))
+                .unwrap();
 
         let response = client.get("/1/datasets", None).await;
         assert!(response.is_err());
@@ -268,7 +690,8 @@ mod connectivity {
             None,
             Some("test-key".to_string()),
             Some("http://192.0.2.1".to_string()), // RFC5737 test IP
-        );
+        )
+        .unwrap();
 
         // Set a timeout to prevent the test from hanging
         let timeout_duration = std::time::Duration::from_secs(5);
@@ -288,7 +711,8 @@ mod connectivity {
             None,
             Some("test-key".to_string()),
             Some("http://this-domain-does-not-exist-12345.com".to_string()),
-        );
+        )
+        .unwrap();
 
         let response = client.get("/1/datasets", None).await;
         assert!(response.is_err());
@@ -312,7 +736,8 @@ mod query_parameters {
             .await;
 
         let client =
-            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()));
+            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()))
+                .unwrap();
 
         let mut params = HashMap::new();
         params.insert(
@@ -336,7 +761,8 @@ mod query_parameters {
             .await;
 
         let client =
-            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()));
+            HoneycombClient::new(None, Some("test-key".to_string()), Some(mock_server.uri()))
+                .unwrap();
 
         let mut params = HashMap::new();
         params.insert(
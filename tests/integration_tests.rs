@@ -1,5 +1,10 @@
 use assert_cmd::Command;
 use predicates::prelude::*;
+use serde_json::json;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
 
 /// Test CLI argument validation for datasets list command
 #[tokio::test]
@@ -15,7 +20,8 @@ async fn test_datasets_list_requires_team_and_environment() {
     cmd.arg("datasets")
         .arg("list")
         .assert()
-        .failure()
+        // A missing team is a configuration error
+        .code(8)
         .stderr(predicate::str::contains(
             "Team is required. Use --team flag or set HONEYCOMB_TEAM environment variable.",
         ));
@@ -143,3 +149,223 @@ async fn test_version_command() {
         .stdout(predicate::str::contains("apiary"))
         .stdout(predicate::str::is_empty().not());
 }
+
+/// Test that configuration errors map to a stable exit code
+#[tokio::test]
+async fn test_insecure_api_url_exit_code() {
+    let mut cmd = Command::new("cargo");
+    cmd.args(["run", "--"]);
+
+    cmd.env_remove("ALLOW_INSECURE_HONEYCOMB_TEST_URLS");
+
+    cmd.args(["--api-url", "http://api.honeycomb.io", "auth", "info"])
+        .assert()
+        .code(8)
+        .stderr(predicate::str::contains("Insecure HTTP API URL"));
+}
+
+/// Test that --dry-run prints the request with keys redacted and exits successfully
+#[tokio::test]
+async fn test_dry_run_prints_redacted_curl() {
+    let mut cmd = Command::new("cargo");
+    cmd.args(["run", "--"]);
+
+    cmd.env("HONEYCOMB_CONFIGURATION_API_KEY", "hcaik_supersecret");
+
+    cmd.args([
+        "--dry-run=curl",
+        "triggers",
+        "delete",
+        "--dataset",
+        "my-dataset",
+        "--id",
+        "trigger-123",
+    ])
+    .assert()
+    .success()
+    // Nothing was deleted, so there is no success message
+    .stdout(predicate::str::is_empty())
+    .stderr(predicate::str::contains(
+        "curl -X DELETE 'https://api.honeycomb.io/1/triggers/my-dataset/trigger-123'",
+    ))
+    .stderr(predicate::str::contains("$HONEYCOMB_CONFIGURATION_API_KEY"))
+    .stderr(predicate::str::contains("hcaik_supersecret").not())
+    .stderr(predicate::str::contains(
+        "Dry run: 1 request(s) printed, not sent",
+    ));
+}
+
+/// Test that verbose logging handles short keys without panicking or leaking them
+#[tokio::test]
+async fn test_verbose_with_short_key() {
+    let mut cmd = Command::new("cargo");
+    cmd.args(["run", "--"]);
+
+    cmd.args([
+        "-vv",
+        "--config-key",
+        "abc",
+        "--api-url",
+        "https://127.0.0.1:1",
+        "--max-retries",
+        "0",
+        "api",
+        "GET",
+        "/1/datasets",
+    ])
+    .assert()
+    .code(1)
+    .stderr(predicate::str::contains("Configuration Key: <redacted>"))
+    .stderr(predicate::str::contains("x-honeycomb-team: <redacted>"))
+    .stderr(predicate::str::contains("panicked").not())
+    // stderr is a pipe here, so no color escape codes
+    .stderr(predicate::str::contains("\x1b[").not());
+}
+
+/// Test that auth info masks keys instead of printing a prefix of the secret
+#[tokio::test]
+async fn test_auth_info_masks_keys() {
+    let mut cmd = Command::new("cargo");
+    cmd.args(["run", "--"]);
+
+    cmd.env("APIARY_CONFIG_FILE", "/nonexistent/apiary/config.toml");
+
+    cmd.args([
+        "--management-key-id",
+        "hcxmk_abc",
+        "--management-key-secret",
+        "topsecret",
+        "--config-key",
+        "ééééé",
+        "auth",
+        "info",
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        "Management Key: hcxmk_abc:<redacted>",
+    ))
+    .stdout(predicate::str::contains("Configuration Key: <redacted>"))
+    .stdout(predicate::str::contains("topsecret").not());
+}
+
+/// Test that slos report parses and runs end to end, including the CSV switch
+#[tokio::test]
+async fn test_slos_report_cli() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/1/slos/web"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!([{"id": "slo-1", "name": "Uptime"}])),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/1/slos/web/slo-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "slo-1",
+            "name": "Uptime",
+            "target_per_million": 999000,
+            "budget_remaining": 42.5,
+            "compliance": 99.95
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let report = |args: &[&str]| {
+        let mut cmd = Command::new("cargo");
+        cmd.args(["run", "--"]);
+        cmd.env("HONEYCOMB_CONFIGURATION_API_KEY", "hcaik_test_key")
+            .env("APIARY_CONFIG_FILE", "/nonexistent/apiary/config.toml")
+            .env_remove("HONEYCOMB_ENVIRONMENT")
+            .args(["--api-url", &mock_server.uri()])
+            .args(["slos", "report", "--dataset", "web"])
+            .args(args)
+            .assert()
+            .success()
+    };
+
+    report(&[]).stdout(predicate::str::contains("Uptime"));
+    report(&["--format", "json"]).stdout(predicate::str::contains("\"budget_remaining\":42.5"));
+    report(&["--csv"]).stdout(predicate::str::starts_with(
+        "dataset,id,name,target,compliance,budget_remaining\nweb,slo-1,Uptime,99.9,99.95,42.5\n",
+    ));
+}
+
+/// Test that service-map parses and runs end to end in each output mode
+#[tokio::test]
+async fn test_service_map_cli() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/1/maps/dependencies/requests"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "request_id": "map-1",
+            "status": "ready",
+            "dependencies": [{
+                "parent_node": {"name": "frontend", "type": "service"},
+                "child_node": {"name": "checkout", "type": "service"},
+                "call_count": 42
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let service_map = |args: &[&str]| {
+        let mut cmd = Command::new("cargo");
+        cmd.args(["run", "--"]);
+        cmd.env("HONEYCOMB_CONFIGURATION_API_KEY", "hcaik_test_key")
+            .env("APIARY_CONFIG_FILE", "/nonexistent/apiary/config.toml")
+            .env_remove("HONEYCOMB_ENVIRONMENT")
+            .args(["--api-url", &mock_server.uri(), "service-map"])
+            .args(args)
+            .assert()
+            .success()
+    };
+
+    service_map(&[]).stdout(predicate::str::contains("frontend"));
+    service_map(&["--format", "json"]).stdout(predicate::str::contains("\"call_count\":42"));
+    service_map(&["--graph", "dot"]).stdout(predicate::str::contains(
+        r#""frontend" -> "checkout" [label="42"];"#,
+    ));
+}
+
+/// Test that a single page of JSON keeps the API's links and meta, while
+/// --limit/--all output is one data document
+#[tokio::test]
+async fn test_environments_list_json_shape() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/2/teams/my-team/environments"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [{"id": "env-1"}, {"id": "env-2"}],
+            "links": {"next": "/2/teams/my-team/environments?page%5Bafter%5D=env-2"},
+            "meta": {"page": {"size": 2}}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let list = |args: &[&str]| {
+        let mut cmd = Command::new("cargo");
+        cmd.args(["run", "--"]);
+        cmd.env("HONEYCOMB_MANAGEMENT_API_KEY_ID", "hcxmk_test")
+            .env("HONEYCOMB_MANAGEMENT_API_KEY", "secret")
+            .env("APIARY_CONFIG_FILE", "/nonexistent/apiary/config.toml")
+            .args(["--api-url", &mock_server.uri()])
+            .args([
+                "environments",
+                "list",
+                "--team",
+                "my-team",
+                "--format",
+                "json",
+            ])
+            .args(args)
+            .assert()
+            .success()
+    };
+
+    list(&[])
+        .stdout(predicate::str::contains("\"links\":{\"next\""))
+        .stdout(predicate::str::contains("\"meta\""));
+    list(&["--limit", "1"]).stdout("{\"data\":[{\"id\":\"env-1\"}]}\n");
+}
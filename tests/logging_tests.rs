@@ -0,0 +1,93 @@
+//! Tests for secret redaction in request/response logging
+
+use apiary::logging::{REDACTED, mask_key, redact_body, redact_headers, redact_json};
+use reqwest::header::{HeaderMap, HeaderValue};
+use serde_json::json;
+
+#[test]
+fn test_redact_headers_hides_api_keys() {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        "Authorization",
+        HeaderValue::from_static("Bearer id:secret"),
+    );
+    headers.insert("X-Honeycomb-Team", HeaderValue::from_static("hcaik_secret"));
+    headers.insert("Content-Type", HeaderValue::from_static("application/json"));
+
+    let rendered = redact_headers(&headers);
+    assert!(!rendered.contains("secret"));
+    assert!(rendered.contains("authorization: <redacted>"));
+    assert!(rendered.contains("content-type: application/json"));
+}
+
+#[test]
+fn test_redact_json_hides_recipient_secrets() {
+    let recipients = json!([
+        {
+            "id": "r1",
+            "type": "webhook",
+            "details": {
+                "webhook_name": "Deploys",
+                "webhook_url": "https://hooks.example.com/T000/B000/XXXX",
+                "webhook_secret": "shh",
+                "webhook_headers": [{"header": "X-Token", "value": "abc"}]
+            }
+        },
+        {
+            "id": "r2",
+            "type": "pagerduty",
+            "details": {"pagerduty_integration_key": "pd-key", "pagerduty_integration_name": "Ops"}
+        }
+    ]);
+
+    let redacted = redact_json(&recipients);
+    assert_eq!(redacted[0]["details"]["webhook_name"], "Deploys");
+    assert_eq!(redacted[0]["details"]["webhook_url"], REDACTED);
+    assert_eq!(redacted[0]["details"]["webhook_secret"], REDACTED);
+    assert_eq!(redacted[0]["details"]["webhook_headers"], REDACTED);
+    assert_eq!(
+        redacted[1]["details"]["pagerduty_integration_key"],
+        REDACTED
+    );
+    assert_eq!(redacted[1]["details"]["pagerduty_integration_name"], "Ops");
+}
+
+#[test]
+fn test_redact_json_keeps_fields_that_only_mention_secrets() {
+    let key = json!({
+        "data": {
+            "attributes": {
+                "name": "ci",
+                "secret": "hcxik_123",
+                "api_key_access": {"events": true},
+                "token_count": 3,
+                "request_headers": ["x-request-id"]
+            }
+        }
+    });
+
+    let attributes = &redact_json(&key)["data"]["attributes"];
+    assert_eq!(attributes["secret"], REDACTED);
+    assert_eq!(attributes["api_key_access"], json!({"events": true}));
+    assert_eq!(attributes["token_count"], 3);
+    assert_eq!(attributes["request_headers"], json!(["x-request-id"]));
+}
+
+#[test]
+fn test_redact_body_passes_through_non_json() {
+    assert_eq!(redact_body("Bad Gateway"), "Bad Gateway");
+    assert!(
+        !redact_body(r#"{"data":{"attributes":{"secret":"hcxik_123"}}}"#).contains("hcxik_123")
+    );
+}
+
+#[test]
+fn test_mask_key_never_panics_or_leaks() {
+    assert_eq!(mask_key(""), REDACTED);
+    assert_eq!(mask_key("short"), REDACTED);
+    assert_eq!(mask_key("hcxmk_abc:topsecret"), "hcxmk_abc:<redacted>");
+    assert_eq!(mask_key("hcaik_0123456789abcdef"), "hcaik_01...");
+    // Multi-byte characters must not cause a slicing panic
+    assert_eq!(mask_key("ééééééééééé"), "éééé...");
+    assert_eq!(mask_key("aéééééééééé"), REDACTED);
+}
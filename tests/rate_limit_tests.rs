@@ -0,0 +1,148 @@
+//! Tests for client-side rate limiting and bounded concurrency
+
+use apiary::client::HoneycombClient;
+use apiary::errors::ApiaryError;
+use apiary::rate_limit::{RateLimitConfig, RateLimiter};
+use apiary::transport::{HttpRequest, HttpResponse, Transport, TransportFuture};
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Transport that takes a while to answer and tracks how many requests overlap
+#[derive(Debug, Default)]
+struct SlowTransport {
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+}
+
+impl Transport for SlowTransport {
+    fn send(&self, _request: HttpRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(current, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(HttpResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: b"{}".to_vec(),
+            })
+        })
+    }
+}
+
+fn client_with(
+    transport: Arc<SlowTransport>,
+    config: RateLimitConfig,
+    management: RateLimitConfig,
+) -> HoneycombClient {
+    HoneycombClient::builder()
+        .management_key(Some("hcxmk_test:secret".to_string()))
+        .config_key(Some("hcaik_test".to_string()))
+        .config_rate_limit(config)
+        .management_rate_limit(management)
+        .transport(transport)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_token_bucket_spaces_requests_after_burst() {
+    let limiter = RateLimiter::new(RateLimitConfig {
+        requests_per_second: 50.0,
+        burst: 2,
+        max_concurrency: 10,
+    });
+
+    let started = Instant::now();
+    for _ in 0..2 {
+        limiter.acquire().await;
+    }
+    assert!(started.elapsed() < Duration::from_millis(15));
+
+    // Three more requests at 50/s need at least 60ms of refill
+    for _ in 0..3 {
+        limiter.acquire().await;
+    }
+    assert!(started.elapsed() >= Duration::from_millis(55));
+}
+
+#[tokio::test]
+async fn test_zero_rate_disables_rate_limiting() {
+    let limiter = RateLimiter::new(RateLimitConfig::new(0.0, 1));
+
+    let started = Instant::now();
+    for _ in 0..100 {
+        let permit = limiter.acquire().await;
+        assert!(permit.waited < Duration::from_millis(10));
+    }
+    assert!(started.elapsed() < Duration::from_millis(100));
+}
+
+#[tokio::test]
+async fn test_concurrency_cap_is_shared_by_client_clones() {
+    let transport = Arc::new(SlowTransport::default());
+    let client = client_with(
+        transport.clone(),
+        RateLimitConfig::new(0.0, 2),
+        RateLimitConfig::new(0.0, 2),
+    );
+
+    let mut tasks = tokio::task::JoinSet::new();
+    for i in 0..8 {
+        let client = client.clone();
+        tasks.spawn(async move { client.get(&format!("/1/triggers/ds{}", i), None).await });
+    }
+    while let Some(result) = tasks.join_next().await {
+        result.unwrap().unwrap();
+    }
+
+    assert_eq!(transport.max_in_flight.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_api_families_are_limited_independently() {
+    let transport = Arc::new(SlowTransport::default());
+    let client = client_with(
+        transport,
+        // Exhausted after one request for the rest of the test
+        RateLimitConfig {
+            requests_per_second: 0.01,
+            burst: 1,
+            max_concurrency: 1,
+        },
+        RateLimitConfig::new(0.0, 4),
+    );
+
+    client.get("/1/datasets", None).await.unwrap();
+
+    let started = Instant::now();
+    for _ in 0..3 {
+        client.get("/2/teams/t/environments", None).await.unwrap();
+    }
+    assert!(started.elapsed() < Duration::from_secs(1));
+}
+
+#[test]
+fn test_invalid_rate_limits_are_config_errors() {
+    for limit in [
+        RateLimitConfig::new(-1.0, 1),
+        RateLimitConfig::new(f64::NAN, 1),
+        RateLimitConfig::new(5.0, 0),
+    ] {
+        let err = HoneycombClient::builder()
+            .config_rate_limit(limit)
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, ApiaryError::ConfigError(_)), "{:?}", err);
+    }
+}
+
+#[test]
+fn test_default_burst_is_one_second_of_requests() {
+    assert_eq!(RateLimitConfig::new(10.0, 4).burst, 10);
+    assert_eq!(RateLimitConfig::new(0.5, 4).burst, 1);
+    assert_eq!(RateLimitConfig::config_api().max_concurrency, 4);
+}
@@ -0,0 +1,205 @@
+//! Tests for region shortcuts and region probing
+
+use apiary::client::HoneycombClient;
+use apiary::config::{ConfigFile, Profile};
+use apiary::errors::ApiaryError;
+use apiary::region::{KeyProbe, Region, probe_regions, resolve_api_url};
+use assert_cmd::Command;
+use predicates::prelude::*;
+use serde_json::json;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{method, path},
+};
+
+#[test]
+fn test_api_url_precedence() {
+    assert_eq!(
+        resolve_api_url(None, Some(Region::Eu), None).as_deref(),
+        Some("https://api.eu1.honeycomb.io")
+    );
+    assert_eq!(
+        resolve_api_url(
+            Some("https://proxy.example.com".to_string()),
+            Some(Region::Eu),
+            None
+        )
+        .as_deref(),
+        Some("https://proxy.example.com")
+    );
+    assert_eq!(
+        resolve_api_url(
+            None,
+            Some(Region::Us),
+            Some("api.eu1.honeycomb.io".to_string())
+        )
+        .as_deref(),
+        Some("https://api.honeycomb.io")
+    );
+    assert_eq!(
+        resolve_api_url(None, None, Some("api.eu1.honeycomb.io".to_string())).as_deref(),
+        Some("https://api.eu1.honeycomb.io")
+    );
+    assert_eq!(resolve_api_url(None, None, None), None);
+}
+
+#[tokio::test]
+async fn test_probe_reports_region_accepting_keys() {
+    let us = MockServer::start().await;
+    for auth in ["/1/auth", "/2/auth"] {
+        Mock::given(method("GET"))
+            .and(path(auth))
+            .respond_with(
+                ResponseTemplate::new(401).set_body_json(json!({"error": "unknown API key"})),
+            )
+            .mount(&us)
+            .await;
+    }
+    let eu = MockServer::start().await;
+    for auth in ["/1/auth", "/2/auth"] {
+        Mock::given(method("GET"))
+            .and(path(auth))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+            .mount(&eu)
+            .await;
+    }
+
+    let client = HoneycombClient::new(
+        Some("hcxmk_id:secret".to_string()),
+        Some("hcaik_key".to_string()),
+        Some(us.uri()),
+    )
+    .unwrap();
+    let probes = probe_regions(
+        &client,
+        &[("us".to_string(), us.uri()), ("eu".to_string(), eu.uri())],
+    )
+    .await;
+
+    assert_eq!(probes[0].management, Some(KeyProbe::Rejected));
+    assert_eq!(probes[0].configuration, Some(KeyProbe::Rejected));
+    assert!(!probes[0].accepted());
+    assert_eq!(probes[1].management, Some(KeyProbe::Accepted));
+    assert!(probes[1].accepted());
+}
+
+#[tokio::test]
+async fn test_probe_only_checks_configured_keys() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/1/auth"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client =
+        HoneycombClient::new(None, Some("hcaik_key".to_string()), Some(server.uri())).unwrap();
+    let probes = probe_regions(&client, &[("us".to_string(), server.uri())]).await;
+
+    assert_eq!(probes[0].management, None);
+    assert_eq!(probes[0].configuration, Some(KeyProbe::Accepted));
+}
+
+#[test]
+fn test_profile_region_is_validated() {
+    let mut profile = Profile::default();
+    profile.set("region", Some("eu".to_string())).unwrap();
+    assert_eq!(profile.get("region").unwrap(), Some("eu"));
+    assert!(
+        profile
+            .shell_exports()
+            .contains("export HONEYCOMB_REGION='eu'\n")
+    );
+
+    assert!(matches!(
+        profile.set("region", Some("mars".to_string())),
+        Err(ApiaryError::ValidationError(_))
+    ));
+}
+
+#[test]
+fn test_region_flag_selects_api_host() {
+    let mut cmd = Command::new("cargo");
+    cmd.args(["run", "--"]);
+    cmd.env("HONEYCOMB_CONFIGURATION_API_KEY", "hcaik_test_key")
+        .env("APIARY_CONFIG_FILE", "/nonexistent/apiary/config.toml")
+        .env_remove("HONEYCOMB_API_URL")
+        .env_remove("HONEYCOMB_API_ENDPOINT")
+        .env_remove("HONEYCOMB_ENVIRONMENT")
+        .args([
+            "--region",
+            "eu",
+            "--dry-run",
+            "markers",
+            "delete",
+            "--dataset",
+            "web",
+            "--id",
+            "m1",
+        ])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Host: api.eu1.honeycomb.io"));
+}
+
+#[test]
+fn test_flags_beat_profile_and_environment_hosts() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("config.toml");
+    let mut file = ConfigFile::default();
+    file.profiles.insert(
+        "us".to_string(),
+        Profile {
+            region: Some("us".to_string()),
+            ..Profile::default()
+        },
+    );
+    file.profiles.insert(
+        "proxy".to_string(),
+        Profile {
+            api_url: Some("https://proxy.example.com".to_string()),
+            ..Profile::default()
+        },
+    );
+    file.save(&config).unwrap();
+
+    let host = |profile: Option<&str>, args: &[&str]| {
+        let mut cmd = Command::new("cargo");
+        cmd.args(["run", "--"]);
+        cmd.env("HONEYCOMB_CONFIGURATION_API_KEY", "hcaik_test_key")
+            .env("APIARY_CONFIG_FILE", &config)
+            .env("HONEYCOMB_REGION", "us")
+            .env_remove("APIARY_PROFILE")
+            .env_remove("HONEYCOMB_API_URL")
+            .env_remove("HONEYCOMB_API_ENDPOINT")
+            .env_remove("HONEYCOMB_ENVIRONMENT");
+        if let Some(profile) = profile {
+            cmd.args(["--profile", profile]);
+        }
+        cmd.args(args)
+            .args([
+                "--dry-run",
+                "markers",
+                "delete",
+                "--dataset",
+                "web",
+                "--id",
+                "m1",
+            ])
+            .assert()
+            .success()
+    };
+
+    // Profile region vs. endpoint flag
+    host(Some("us"), &["--api-endpoint", "api.eu1.honeycomb.io"])
+        .stderr(predicate::str::contains("Host: api.eu1.honeycomb.io"));
+    // Profile URL vs. region flag
+    host(Some("proxy"), &["--region", "eu"])
+        .stderr(predicate::str::contains("Host: api.eu1.honeycomb.io"));
+    // HONEYCOMB_REGION vs. endpoint flag
+    host(None, &["--api-endpoint", "api.eu1.honeycomb.io"])
+        .stderr(predicate::str::contains("Host: api.eu1.honeycomb.io"));
+    // Without flags the profile still applies
+    host(Some("proxy"), &[]).stderr(predicate::str::contains("Host: proxy.example.com"));
+}
@@ -19,6 +19,7 @@ pub fn create_test_client(mock_server_uri: String) -> HoneycombClient {
         Some("test-config-key".to_string()),
         Some(mock_server_uri),
     )
+    .unwrap()
 }
 
 /// Setup standard mock for successful list responses
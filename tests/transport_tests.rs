@@ -0,0 +1,245 @@
+//! Tests for pluggable transports and record/replay cassettes
+
+use apiary::cassette::{Cassette, RecordingTransport, ReplayTransport};
+use apiary::client::HoneycombClient;
+use apiary::transport::{HttpRequest, HttpResponse, Transport, TransportFuture};
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use serde_json::{Value, json};
+use std::sync::{Arc, Mutex};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{header, method, path},
+};
+
+const MANAGEMENT_KEY: &str = "hcxmk_test:supersecretvalue";
+const CONFIG_KEY: &str = "hcaik_configkeyvalue0123456789";
+
+/// In-memory transport that answers every request with the same JSON body
+#[derive(Debug)]
+struct FakeTransport {
+    body: Value,
+    requests: Mutex<Vec<HttpRequest>>,
+}
+
+impl Transport for FakeTransport {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            self.requests.lock().unwrap().push(request);
+            Ok(HttpResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: serde_json::to_vec(&self.body)?,
+            })
+        })
+    }
+}
+
+fn client_with(transport: Arc<dyn Transport>) -> HoneycombClient {
+    HoneycombClient::builder()
+        .management_key(Some(MANAGEMENT_KEY.to_string()))
+        .config_key(Some(CONFIG_KEY.to_string()))
+        .base_url(Some("https://api.honeycomb.io".to_string()))
+        .transport(transport)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_fake_transport_receives_authenticated_requests() {
+    let fake = Arc::new(FakeTransport {
+        body: json!([{"slug": "web"}]),
+        requests: Mutex::new(Vec::new()),
+    });
+    let client = client_with(fake.clone());
+
+    let result = client.get("/1/datasets", None).await.unwrap();
+    assert_eq!(result, json!([{"slug": "web"}]));
+
+    let requests = fake.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path_and_query(), "/1/datasets");
+    assert_eq!(requests[0].headers["X-Honeycomb-Team"], CONFIG_KEY);
+}
+
+#[tokio::test]
+async fn test_record_then_replay_offline() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/2/teams/my-team/environments"))
+        .and(header(
+            "Authorization",
+            format!("Bearer {}", MANAGEMENT_KEY),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [{"id": "env1", "type": "environments"}],
+            // Echoed secrets must not end up in the cassette
+            "meta": {"echo": "supersecretvalue"}
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/1/markers/production"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({"id": "m1"})))
+        .mount(&mock_server)
+        .await;
+
+    let dir = tempfile::tempdir().unwrap();
+    let cassette_path = dir.path().join("cassette.json");
+
+    let recording = HoneycombClient::builder()
+        .management_key(Some(MANAGEMENT_KEY.to_string()))
+        .config_key(Some(CONFIG_KEY.to_string()))
+        .base_url(Some(mock_server.uri()))
+        .record_cassette(Some(cassette_path.clone()))
+        .build()
+        .unwrap();
+    let live_envs = recording
+        .get("/2/teams/my-team/environments", None)
+        .await
+        .unwrap();
+    let live_marker = recording
+        .post("/1/markers/production", &json!({"message": "deploy"}))
+        .await
+        .unwrap();
+
+    let saved = std::fs::read_to_string(&cassette_path).unwrap();
+    assert!(!saved.contains("supersecretvalue"));
+    assert!(!saved.contains(CONFIG_KEY));
+    assert!(saved.contains("\"message\": \"deploy\""));
+
+    drop(mock_server);
+    let replay = ReplayTransport::from_file(&cassette_path).unwrap();
+    let client = client_with(Arc::new(replay));
+    assert_eq!(
+        client
+            .get("/2/teams/my-team/environments", None)
+            .await
+            .unwrap(),
+        json!({
+            "data": [{"id": "env1", "type": "environments"}],
+            "meta": {"echo": "<redacted>"}
+        })
+    );
+    assert_eq!(
+        live_envs["data"],
+        json!([{"id": "env1", "type": "environments"}])
+    );
+    assert_eq!(
+        client
+            .post("/1/markers/production", &json!({"message": "deploy"}))
+            .await
+            .unwrap(),
+        live_marker
+    );
+}
+
+#[tokio::test]
+async fn test_replay_returns_interactions_in_order() {
+    let cassette: Cassette = serde_json::from_value(json!({
+        "interactions": [
+            {"request": {"method": "GET", "path": "/1/auth"}, "response": {"status": 200, "body": {"n": 1}}},
+            {"request": {"method": "GET", "path": "/1/auth"}, "response": {"status": 200, "body": {"n": 2}}}
+        ]
+    }))
+    .unwrap();
+    let client = client_with(Arc::new(ReplayTransport::new(cassette)));
+
+    assert_eq!(client.get("/1/auth", None).await.unwrap()["n"], 1);
+    assert_eq!(client.get("/1/auth", None).await.unwrap()["n"], 2);
+    // Once exhausted, the last matching interaction is repeated
+    assert_eq!(client.get("/1/auth", None).await.unwrap()["n"], 2);
+}
+
+#[tokio::test]
+async fn test_replay_replays_error_statuses() {
+    let cassette: Cassette = serde_json::from_value(json!({
+        "interactions": [
+            {"request": {"method": "GET", "path": "/1/datasets/gone"}, "response": {"status": 404, "body": {"error": "not found"}}}
+        ]
+    }))
+    .unwrap();
+    let client = client_with(Arc::new(ReplayTransport::new(cassette)));
+
+    let err = client.get("/1/datasets/gone", None).await.unwrap_err();
+    assert!(format!("{:#}", err).contains("not found"));
+}
+
+#[tokio::test]
+async fn test_replay_without_matching_interaction_fails() {
+    let client = client_with(Arc::new(ReplayTransport::new(Cassette::default())));
+
+    let err = client.get("/1/datasets", None).await.unwrap_err();
+    assert!(
+        format!("{:#}", err).contains("No recorded response for GET /1/datasets"),
+        "unexpected error: {:#}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn test_recording_wraps_any_transport() {
+    let dir = tempfile::tempdir().unwrap();
+    let cassette_path = dir.path().join("fake.json");
+    let fake = Arc::new(FakeTransport {
+        body: json!({"ok": true}),
+        requests: Mutex::new(Vec::new()),
+    });
+    let recording = RecordingTransport::new(fake, &cassette_path);
+    let client = client_with(Arc::new(recording));
+
+    let mut params = std::collections::HashMap::new();
+    params.insert("b".to_string(), "2".to_string());
+    params.insert("a".to_string(), "1".to_string());
+    client.get("/1/columns/web", Some(&params)).await.unwrap();
+
+    let cassette = Cassette::load(&cassette_path).unwrap();
+    assert_eq!(cassette.interactions.len(), 1);
+    // Query parameters are sorted so recordings match deterministically
+    assert_eq!(
+        cassette.interactions[0].request.path,
+        "/1/columns/web?a=1&b=2"
+    );
+    assert_eq!(
+        cassette.interactions[0].response.body,
+        Some(json!({"ok": true}))
+    );
+}
+
+#[tokio::test]
+async fn test_recording_redacts_created_api_key_secret() {
+    let dir = tempfile::tempdir().unwrap();
+    let cassette_path = dir.path().join("api_keys.json");
+    let fake = Arc::new(FakeTransport {
+        body: json!({
+            "data": {
+                "id": "hcxik_01new",
+                "type": "api-keys",
+                "attributes": {
+                    "name": "ingest",
+                    "key_type": "ingest",
+                    "secret": "newlycreatedsecretvalue"
+                }
+            }
+        }),
+        requests: Mutex::new(Vec::new()),
+    });
+    let client = client_with(Arc::new(RecordingTransport::new(fake, &cassette_path)));
+
+    let created = client
+        .post(
+            "/2/teams/my-team/api-keys",
+            &json!({"data": {"type": "api-keys", "attributes": {"name": "ingest"}}}),
+        )
+        .await
+        .unwrap();
+    // The caller still gets the real secret; only the recording is redacted
+    assert_eq!(
+        created["data"]["attributes"]["secret"],
+        "newlycreatedsecretvalue"
+    );
+
+    let saved = std::fs::read_to_string(&cassette_path).unwrap();
+    assert!(!saved.contains("newlycreatedsecretvalue"), "{}", saved);
+    assert!(saved.contains("hcxik_01new"), "{}", saved);
+}